@@ -1,30 +1,34 @@
 use std::collections::HashSet;
 
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = get_puzzle_input(2018, 1);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 1))?;
+    let freq_changes = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&freq_changes));
+    let p2 = timer.part2(|| part2(&freq_changes));
+    Ok(Solution::new(p1, p2))
+}
+
+fn parse(input: &str) -> Vec<isize> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+fn part1(freq_changes: &[isize]) -> isize {
+    freq_changes.iter().sum()
+}
+
+fn part2(freq_changes: &[isize]) -> isize {
     let mut sum: isize = 0;
     let mut seen: HashSet<isize> = HashSet::new();
-    let mut freq_changes: Vec<isize> = Vec::new();
-    for line in input.lines() {
-        let freq: isize = line.parse().unwrap();
-        freq_changes.push(freq);
-    }
-    let mut flag = true;
-    'big: loop {
-        for i in &freq_changes {
+    loop {
+        for i in freq_changes {
             seen.insert(sum);
             sum += i;
             if seen.contains(&sum) {
-                println!("part 2 sum {}", sum);
-                break 'big;
+                return sum;
             }
         }
-        if flag {
-            println!("part 1 sum {}", sum);
-            flag = false;
-        }
     }
-    // println!("{:?}", seen);
 }
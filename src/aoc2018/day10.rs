@@ -1,65 +1,101 @@
 use std::fmt;
 
-use crate::util::get_puzzle_input;
+use crate::{
+    grid::Grid,
+    ocr,
+    point::P2,
+    util::{get_puzzle_input, Error},
+};
 
 use regex::Regex;
+use year_lib::{Solution, Timer};
 
-struct Point {
-    x: i32,
-    y: i32,
-    vel_x: i32,
-    vel_y: i32,
+struct Light {
+    position: P2,
+    velocity: P2,
 }
 
-impl Point {
-    fn new(x: i32, y: i32, vel_x: i32, vel_y: i32) -> Self {
-        Point { x, y, vel_x, vel_y }
-    }
+impl Light {
     fn update(&mut self) {
-        self.x += self.vel_x;
-        self.y += self.vel_y;
+        self.position += self.velocity;
+    }
+    fn revert(&mut self) {
+        self.position -= self.velocity;
     }
 }
 
-impl fmt::Display for Point {
+impl fmt::Display for Light {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "p({}, {}) v({} {})",
-            self.x, self.y, self.vel_x, self.vel_y
+            self.position.x(),
+            self.position.y(),
+            self.velocity.x(),
+            self.velocity.y()
         )
     }
 }
 
-pub fn run() {
-    let input = get_puzzle_input(2018, 10);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 10))?;
+    let mut points = timer.parse(|| parse(&input));
+    // the seconds are the answer of part 2, but the message of part 1 needs the points aligned
+    let seconds = timer.part2(|| align(&mut points));
+    let message = timer.part1(|| ocr::read(&image(&points)));
+    Ok(Solution::new(message, seconds))
+}
+
+fn parse(input: &str) -> Vec<Light> {
     let re = Regex::new(r"position=<( *-?\d+), ( *-?\d+)> velocity=<( *-?\d), ( *-?\d)>").unwrap();
     let mut v = vec![];
     for line in input.lines() {
         let c = re.captures(line.trim()).unwrap();
-        let p = Point::new(
-            c[1].trim().parse::<i32>().unwrap(),
-            c[2].trim().parse::<i32>().unwrap(),
-            c[3].trim().parse::<i32>().unwrap(),
-            c[4].trim().parse::<i32>().unwrap(),
-        );
+        let field = |i: usize| c[i].trim().parse().unwrap();
+        let p = Light {
+            position: P2::new(field(1), field(2)),
+            velocity: P2::new(field(3), field(4)),
+        };
         v.push(p);
     }
-    for it in 0..10942 {
+    v
+}
+
+/// Moves the points until the message appears and returns the number of seconds it took
+fn align(v: &mut [Light]) -> usize {
+    // points converge until the message appears, then drift apart again
+    let mut seconds = 0;
+    let mut height = bounding_box(v).3;
+    loop {
         v.iter_mut().for_each(|p| p.update());
-        let x_min = v.iter().min_by_key(|&p| p.x).unwrap();
-        let y_min = v.iter().min_by_key(|&p| p.y).unwrap();
-        let x_max = v.iter().max_by_key(|&p| p.x).unwrap();
-        let y_max = v.iter().max_by_key(|&p| p.y).unwrap();
-        println!("{} {} {} {} {}", it, x_max.x, x_min.x, y_max.y, y_min.y);
-    }
-    let mut board = vec![vec![" "; 62]; 15];
-    v.iter()
-        .for_each(|p| board[p.y as usize - 140][p.x as usize - 167] = "X");
-    for i in board.iter_mut() {
-        for j in i {
-            print!("{}", *j);
+        let next_height = bounding_box(v).3;
+        if next_height > height {
+            v.iter_mut().for_each(|p| p.revert());
+            return seconds;
         }
-        println!();
+        height = next_height;
+        seconds += 1;
     }
 }
+
+/// Returns (x_min, y_min, width, height) of the area covered by the points
+fn bounding_box(v: &[Light]) -> (i64, i64, i64, i64) {
+    let x_min = v.iter().map(|p| p.position.x()).min().unwrap();
+    let y_min = v.iter().map(|p| p.position.y()).min().unwrap();
+    let x_max = v.iter().map(|p| p.position.x()).max().unwrap();
+    let y_max = v.iter().map(|p| p.position.y()).max().unwrap();
+    (x_min, y_min, x_max - x_min + 1, y_max - y_min + 1)
+}
+
+/// The points in their bounding box
+fn image(v: &[Light]) -> Grid<bool> {
+    let (x_min, y_min, width, height) = bounding_box(v);
+    let mut image = Grid::filled(width as usize, height as usize, false);
+    v.iter().for_each(|p| {
+        image[(
+            (p.position.x() - x_min) as usize,
+            (p.position.y() - y_min) as usize,
+        )] = true
+    });
+    image
+}
@@ -1,9 +1,21 @@
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input: usize = get_puzzle_input(2018, 11).parse().unwrap();
-    part1(input);
-    part2_summed(input);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 11))?;
+    let serial: usize = timer.parse(|| input.parse().unwrap());
+    let p1 = timer.part1(|| part1(serial));
+    let p2 = timer.part2(|| part2_summed(serial));
+    Ok(Solution::new(p1, p2))
+}
+
+/// Sums the squares cell by cell instead of reading them from a summed-area table
+pub fn run_naive(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 11))?;
+    let serial: usize = timer.parse(|| input.parse().unwrap());
+    let p1 = timer.part1(|| part1(serial));
+    let p2 = timer.part2(|| part2_naive(serial));
+    Ok(Solution::new(p1, p2))
 }
 
 fn default_fuel_matrix(input: usize) -> Vec<Vec<isize>> {
@@ -32,7 +44,7 @@ fn summed_area_fuel_matrix(input: usize) -> Vec<Vec<isize>> {
     fuel_matrix
 }
 
-fn part1(input: usize) {
+fn part1(input: usize) -> String {
     let fuel_matrix = default_fuel_matrix(input);
     let mut max_fuel = isize::MIN;
     let (mut x, mut y) = (None, None);
@@ -53,14 +65,10 @@ fn part1(input: usize) {
     }
 
     // println!("{:?}", fuel_matrix);
-    if let Some(i) = x {
-        if let Some(j) = y {
-            println!("{},{}", i, j);
-        }
-    }
+    format!("{},{}", x.unwrap(), y.unwrap())
 }
 
-fn part2_summed(input: usize) {
+fn part2_summed(input: usize) -> String {
     let fuel_matrix = summed_area_fuel_matrix(input);
     let mut max_fuel = isize::MIN;
     let (mut x, mut y) = (None, None);
@@ -72,38 +80,29 @@ fn part2_summed(input: usize) {
                     + fuel_matrix[i - s][j - s];
                 if fuel > max_fuel {
                     max_fuel = fuel;
-                    x = Some(i);
-                    y = Some(j);
+                    // the square ends at (i, j)
+                    x = Some(i - s + 1);
+                    y = Some(j - s + 1);
                     size = s;
                 }
             }
         }
     }
-    if let Some(i) = x {
-        if let Some(j) = y {
-            println!("{},{},{}", i, j, size);
-        }
-    }
+    format!("{},{},{}", x.unwrap(), y.unwrap(), size)
 }
 
-#[allow(dead_code)]
-fn part2_naive(input: usize) {
+fn part2_naive(input: usize) -> String {
     let fuel_matrix = default_fuel_matrix(input);
     let mut max_fuel = isize::MIN;
     let (mut x, mut y) = (None, None);
     let mut size = 0;
-    for i in 0..=298 {
-        for j in 0..=298 {
-            'm: for s in 2..299 {
-                let mut fuel = 0;
-                for x in 0..s {
-                    for y in 0..s {
-                        if i + x > 299 || j + y > 299 {
-                            break 'm;
-                        }
-                        fuel += fuel_matrix[i + x][j + y];
-                    }
-                }
+    for i in 1..300 {
+        for j in 1..300 {
+            // grows the square from its top left corner by a column and a row at a time
+            let mut fuel = 0;
+            for s in 1..=300 - i.max(j) {
+                fuel += (j..j + s).map(|y| fuel_matrix[i + s - 1][y]).sum::<isize>()
+                    + (i..i + s - 1).map(|x| fuel_matrix[x][j + s - 1]).sum::<isize>();
                 if fuel > max_fuel {
                     max_fuel = fuel;
                     x = Some(i);
@@ -113,11 +112,22 @@ fn part2_naive(input: usize) {
             }
         }
     }
+    format!("{},{},{}", x.unwrap(), y.unwrap(), size)
+}
 
-    // println!("{:?}", fuel_matrix);
-    if let Some(i) = x {
-        if let Some(j) = y {
-            println!("{},{},{}", i, j, size);
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        assert_eq!(part1(18), "33,45");
+        assert_eq!(part1(42), "21,61");
+    }
+
+    #[test]
+    fn test_2() {
+        assert_eq!(part2_summed(18), "90,269,16");
+        assert_eq!(part2_summed(42), "232,251,12");
     }
 }
@@ -1,12 +1,13 @@
+use crate::util::Error;
 use std::collections::HashMap;
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2018, 12);
-    let garden = parse_input(&input);
-    let p1 = part1(garden.clone());
-    println!("p1 {}", p1);
-    let p2 = part2(garden);
-    println!("p2 {}", p2);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2018, 12))?;
+    let garden = timer.parse(|| parse_input(&input));
+    let p1 = timer.part1(|| part1(garden.clone()));
+    let p2 = timer.part2(|| part2(garden));
+    Ok(Solution::new(p1, p2))
 }
 
 #[derive(Clone)]
@@ -41,7 +42,12 @@ impl Garden {
             }
         }
         let mut clone_state = vec![false; self.state.len()];
-        for (i, item) in clone_state.iter_mut().enumerate().take(self.state.len() - 2).skip(2) {
+        for (i, item) in clone_state
+            .iter_mut()
+            .enumerate()
+            .take(self.state.len() - 2)
+            .skip(2)
+        {
             if self
                 .rules
                 .get(&self.state[i - 2..=i + 2])
@@ -87,9 +93,7 @@ trait MapToBoolVec {
 
 impl MapToBoolVec for &str {
     fn map_to_vec(&self) -> Vec<bool> {
-        self.chars()
-            .map(|c| c == '#')
-            .collect()
+        self.chars().map(|c| c == '#').collect()
     }
 }
 
@@ -132,7 +136,7 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2018_12_test.in");
+        let input = crate::util::read_file("inputs/2018_12_test.in").unwrap();
         let garden = parse_input(&input);
         let p1 = part1(garden);
         assert_eq!(p1, 325);
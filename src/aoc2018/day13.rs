@@ -1,22 +1,29 @@
 use std::collections::HashMap;
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2018, 13);
-    let p1 = part1(&input);
-    println!("p1 {},{}", p1.x, p1.y);
-    let p2 = part2(&input);
-    println!("p1 {},{}", p2.x, p2.y);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+use crate::point::{Direction, P2};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2018, 13))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(
+        format!("{},{}", p1.x(), p1.y()),
+        format!("{},{}", p2.x(), p2.y()),
+    ))
 }
 
-fn part1(input: &str) -> Point {
+fn part1(input: &str) -> P2 {
     solve(input, Strategy::AbortAtFirstCrash)
 }
 
-fn part2(input: &str) -> Point {
+fn part2(input: &str) -> P2 {
     solve(input, Strategy::FindLastCart)
 }
 
-fn solve(input: &str, strategy: Strategy) -> Point {
+fn solve(input: &str, strategy: Strategy) -> P2 {
     let (roads, mut carts) = parse(input, strategy);
     loop {
         if let Some(p) = carts.apply_step(&roads) {
@@ -27,78 +34,42 @@ fn solve(input: &str, strategy: Strategy) -> Point {
 
 #[derive(Debug)]
 struct Road {
-    _location: Point,
-    connections: Vec<Point>,
+    _location: P2,
+    connections: Vec<P2>,
 }
 
 impl Road {
-    fn new(location: Point, connections: Vec<Point>) -> Self {
+    fn new(location: P2, connections: Vec<P2>) -> Self {
         Self {
             _location: location,
             connections,
         }
     }
 }
-#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
-struct Point {
-    x: isize,
-    y: isize,
-}
-
-impl Point {
-    fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-}
-
 #[derive(Debug)]
 struct Cart {
-    location: Point,
+    location: P2,
     current_choice: CurrentChoice,
-    facing: Facing,
+    facing: Direction,
 }
 
 impl Cart {
-    fn new(location: Point, facing: Facing) -> Self {
+    fn new(location: P2, facing: Direction) -> Self {
         Self {
             location,
             current_choice: CurrentChoice::new(),
             facing,
         }
     }
-    fn take_step(&mut self, road_network: &RoadNetwork) -> Point {
-        let next_point;
-        match self.facing {
-            Facing::Up => {
-                next_point = Point::new(self.location.x as isize, self.location.y - 1_isize);
-            }
-            Facing::Down => {
-                next_point = Point::new(self.location.x as isize, self.location.y + 1_isize);
-            }
-            Facing::Left => {
-                next_point = Point::new(self.location.x as isize - 1, self.location.y as isize);
-            }
-            Facing::Right => {
-                next_point = Point::new(self.location.x as isize + 1, self.location.y as isize);
-            }
-        }
+    fn take_step(&mut self, road_network: &RoadNetwork) -> P2 {
+        let next_point = self.location + self.facing.offset();
         // set facing
         if let Some(road) = road_network.roads.get(&next_point) {
             if road.connections.len() == 4 {
                 match self.current_choice {
-                    CurrentChoice::Left => match self.facing {
-                        Facing::Up => self.facing = Facing::Left,
-                        Facing::Down => self.facing = Facing::Right,
-                        Facing::Left => self.facing = Facing::Down,
-                        Facing::Right => self.facing = Facing::Up,
-                    },
+                    CurrentChoice::Left => self.facing = self.facing.turn_left(),
                     CurrentChoice::Straight => {}
-                    CurrentChoice::Right => match self.facing {
-                        Facing::Up => self.facing = Facing::Right,
-                        Facing::Down => self.facing = Facing::Left,
-                        Facing::Left => self.facing = Facing::Up,
-                        Facing::Right => self.facing = Facing::Down,
-                    },
+                    CurrentChoice::Right => self.facing = self.facing.turn_right(),
                 }
                 self.current_choice.next();
             } else if road.connections.len() == 2 {
@@ -114,16 +85,10 @@ impl Cart {
                     .iter()
                     .find(|&&r| r != self.location)
                     .unwrap();
-                match (
-                    next_next_point.x - next_point.x,
-                    next_next_point.y - next_point.y,
-                ) {
-                    (1, 0) => self.facing = Facing::Right,
-                    (-1, 0) => self.facing = Facing::Left,
-                    (0, -1) => self.facing = Facing::Up,
-                    (0, 1) => self.facing = Facing::Down,
-                    (_, _) => unreachable!(),
-                }
+                self.facing = Direction::ALL
+                    .into_iter()
+                    .find(|d| d.offset() == *next_next_point - next_point)
+                    .unwrap();
             } else {
                 unreachable!()
             }
@@ -138,14 +103,6 @@ enum CurrentChoice {
     Straight,
     Right,
 }
-#[derive(Debug)]
-enum Facing {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
 impl CurrentChoice {
     fn new() -> Self {
         CurrentChoice::Left
@@ -160,19 +117,19 @@ impl CurrentChoice {
 }
 #[derive(Debug)]
 struct RoadNetwork {
-    roads: HashMap<Point, Road>,
+    roads: HashMap<P2, Road>,
 }
 
 struct Carts {
-    carts: HashMap<Point, Cart>,
+    carts: HashMap<P2, Cart>,
     solve_strategy: Strategy,
 }
 
 impl Carts {
-    fn apply_step(&mut self, road_network: &RoadNetwork) -> Option<Point> {
+    fn apply_step(&mut self, road_network: &RoadNetwork) -> Option<P2> {
         let mut cart_vec = self.carts.keys().cloned().collect::<Vec<_>>();
         let mut removed = vec![];
-        cart_vec.sort_by_key(|&p| (p.y, p.x));
+        cart_vec.sort_by_key(|&p| (p.y(), p.x()));
         for p in cart_vec {
             if removed.contains(&p) {
                 continue;
@@ -216,7 +173,7 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
     let mut carts = HashMap::new();
     for (j, line) in input.lines().enumerate() {
         for (i, c) in line.chars().enumerate() {
-            let current_point = Point::new(i as isize, j as isize);
+            let current_point = P2::new(i as i64, j as i64);
             match c {
                 '-' => {
                     roads.insert(
@@ -224,8 +181,8 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                         Road::new(
                             current_point,
                             vec![
-                                Point::new(i as isize - 1, j as isize),
-                                Point::new(i as isize + 1, j as isize),
+                                P2::new(i as i64 - 1, j as i64),
+                                P2::new(i as i64 + 1, j as i64),
                             ],
                         ),
                     );
@@ -236,15 +193,15 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                         Road::new(
                             current_point,
                             vec![
-                                Point::new(i as isize, j as isize - 1),
-                                Point::new(i as isize, j as isize + 1),
+                                P2::new(i as i64, j as i64 - 1),
+                                P2::new(i as i64, j as i64 + 1),
                             ],
                         ),
                     );
                 }
                 '/' => {
                     if let Some(_n) = roads
-                        .get(&Point::new(i as isize - 1, j as isize))
+                        .get(&P2::new(i as i64 - 1, j as i64))
                         .filter(|&n| n.connections.contains(&current_point))
                     {
                         roads.insert(
@@ -252,8 +209,8 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                             Road::new(
                                 current_point,
                                 vec![
-                                    Point::new(i as isize - 1, j as isize),
-                                    Point::new(i as isize, j as isize - 1),
+                                    P2::new(i as i64 - 1, j as i64),
+                                    P2::new(i as i64, j as i64 - 1),
                                 ],
                             ),
                         );
@@ -263,8 +220,8 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                             Road::new(
                                 current_point,
                                 vec![
-                                    Point::new(i as isize + 1, j as isize),
-                                    Point::new(i as isize, j as isize + 1),
+                                    P2::new(i as i64 + 1, j as i64),
+                                    P2::new(i as i64, j as i64 + 1),
                                 ],
                             ),
                         );
@@ -272,7 +229,7 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                 }
                 '\\' => {
                     if let Some(_n) = roads
-                        .get(&Point::new(i as isize - 1, j as isize))
+                        .get(&P2::new(i as i64 - 1, j as i64))
                         .filter(|&n| n.connections.contains(&current_point))
                     {
                         roads.insert(
@@ -280,8 +237,8 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                             Road::new(
                                 current_point,
                                 vec![
-                                    Point::new(i as isize - 1, j as isize),
-                                    Point::new(i as isize, j as isize + 1),
+                                    P2::new(i as i64 - 1, j as i64),
+                                    P2::new(i as i64, j as i64 + 1),
                                 ],
                             ),
                         );
@@ -291,8 +248,8 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                             Road::new(
                                 current_point,
                                 vec![
-                                    Point::new(i as isize + 1, j as isize),
-                                    Point::new(i as isize, j as isize - 1),
+                                    P2::new(i as i64 + 1, j as i64),
+                                    P2::new(i as i64, j as i64 - 1),
                                 ],
                             ),
                         );
@@ -304,10 +261,10 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                         Road::new(
                             current_point,
                             vec![
-                                Point::new(i as isize, j as isize - 1),
-                                Point::new(i as isize, j as isize + 1),
-                                Point::new(i as isize + 1, j as isize),
-                                Point::new(i as isize - 1, j as isize),
+                                P2::new(i as i64, j as i64 - 1),
+                                P2::new(i as i64, j as i64 + 1),
+                                P2::new(i as i64 + 1, j as i64),
+                                P2::new(i as i64 - 1, j as i64),
                             ],
                         ),
                     );
@@ -318,12 +275,12 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                         Road::new(
                             current_point,
                             vec![
-                                Point::new(i as isize - 1, j as isize),
-                                Point::new(i as isize + 1, j as isize),
+                                P2::new(i as i64 - 1, j as i64),
+                                P2::new(i as i64 + 1, j as i64),
                             ],
                         ),
                     );
-                    let cart = Cart::new(current_point, Facing::Right);
+                    let cart = Cart::new(current_point, Direction::Right);
                     carts.insert(current_point, cart);
                 }
                 '<' => {
@@ -332,12 +289,12 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                         Road::new(
                             current_point,
                             vec![
-                                Point::new(i as isize - 1, j as isize),
-                                Point::new(i as isize + 1, j as isize),
+                                P2::new(i as i64 - 1, j as i64),
+                                P2::new(i as i64 + 1, j as i64),
                             ],
                         ),
                     );
-                    let cart = Cart::new(current_point, Facing::Left);
+                    let cart = Cart::new(current_point, Direction::Left);
                     carts.insert(current_point, cart);
                 }
                 '^' => {
@@ -346,12 +303,12 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                         Road::new(
                             current_point,
                             vec![
-                                Point::new(i as isize, j as isize - 1),
-                                Point::new(i as isize, j as isize + 1),
+                                P2::new(i as i64, j as i64 - 1),
+                                P2::new(i as i64, j as i64 + 1),
                             ],
                         ),
                     );
-                    let cart = Cart::new(current_point, Facing::Up);
+                    let cart = Cart::new(current_point, Direction::Up);
                     carts.insert(current_point, cart);
                 }
                 'v' => {
@@ -360,12 +317,12 @@ fn parse(input: &str, strategy: Strategy) -> (RoadNetwork, Carts) {
                         Road::new(
                             current_point,
                             vec![
-                                Point::new(i as isize, j as isize - 1),
-                                Point::new(i as isize, j as isize + 1),
+                                P2::new(i as i64, j as i64 - 1),
+                                P2::new(i as i64, j as i64 + 1),
                             ],
                         ),
                     );
-                    let cart = Cart::new(current_point, Facing::Down);
+                    let cart = Cart::new(current_point, Direction::Down);
                     carts.insert(current_point, cart);
                 }
                 ' ' => {}
@@ -388,8 +345,8 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2018_13_test.in");
+        let input = crate::util::read_file("inputs/2018_13_test.in").unwrap();
         let p1 = part1(&input);
-        assert_eq!(p1, Point::new(7, 3));
+        assert_eq!(p1, P2::new(7, 3));
     }
 }
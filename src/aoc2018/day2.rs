@@ -1,12 +1,14 @@
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    part1().unwrap();
-    part2().unwrap();
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 2))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
-fn part2() -> std::io::Result<()> {
-    let input = get_puzzle_input(2018, 2);
+fn part2(input: &str) -> String {
     let mut boxes: Vec<String> = Vec::new();
     for line in input.lines() {
         boxes.push(line.to_string());
@@ -43,13 +45,10 @@ fn part2() -> std::io::Result<()> {
     for i in max_locs.iter() {
         str_chars.remove(*i);
     }
-    let final_str: String = str_chars.iter().collect();
-    println!("part 2 str {}", final_str);
-    Ok(())
+    str_chars.iter().collect()
 }
 
-fn part1() -> std::io::Result<()> {
-    let input = get_puzzle_input(2018, 2);
+fn part1(input: &str) -> usize {
     let mut total_two = 0;
     let mut total_three = 0;
     for line in input.lines() {
@@ -76,6 +75,5 @@ fn part1() -> std::io::Result<()> {
             total_three += 1;
         }
     }
-    println!("part 1 checksum {}", total_two * total_three);
-    Ok(())
+    total_two * total_three
 }
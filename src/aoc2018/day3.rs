@@ -1,35 +1,32 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::util::get_puzzle_input;
+use crate::{
+    point::P2,
+    util::{get_puzzle_input, Error},
+};
+use year_lib::{Solution, Timer};
 
-pub struct Point {
-    value: u8,
+/// Square inch of the fabric and the claims covering it
+#[derive(Default)]
+struct Square {
+    /// Number of claims, counted up to 2
+    claims: u8,
     owners: Vec<String>,
 }
 
-pub fn run() {
-    let input = get_puzzle_input(2018, 3);
-    let mut matrix: Vec<Vec<Point>> = Vec::with_capacity(1000);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 3))?;
+    let (fabric, ids) = timer.parse(|| claim_fabric(&input));
+    let p1 = timer.part1(|| part1(&fabric));
+    let p2 = timer.part2(|| part2(&fabric, ids));
+    Ok(Solution::new(p1, p2))
+}
 
-    //empty 1000x1000 matrix
-    for _i in 0..1000 {
-        let mut a: Vec<Point> = Vec::with_capacity(1000);
-        for _j in 0..1000 {
-            let p = Point {
-                value: 0,
-                owners: Vec::new(),
-            };
-            a.push(p);
-        }
-        matrix.push(a);
-    }
+/// Returns the claimed square inches of the fabric and the ids of all claims
+fn claim_fabric(input: &str) -> (HashMap<P2, Square>, HashSet<String>) {
+    let mut fabric: HashMap<P2, Square> = HashMap::new();
     let mut ids = HashSet::new();
-    let mut count = 0;
     for line in input.lines() {
-        let s_x: usize;
-        let s_y: usize;
-        let x: usize;
-        let y: usize;
         let mut l = line.split_whitespace();
         let id = l.next().unwrap();
         ids.insert(id.to_string());
@@ -37,39 +34,44 @@ pub fn run() {
         let mut skips = l.next().unwrap();
         skips = &skips[0..skips.len() - 1];
         let mut m = skips.split(',');
-        s_x = m.next().unwrap().parse().unwrap();
-        s_y = m.next().unwrap().parse().unwrap();
+        let s_x: i64 = m.next().unwrap().parse().unwrap();
+        let s_y: i64 = m.next().unwrap().parse().unwrap();
         let dims = l.next().unwrap();
         let mut k = dims.split('x');
-        x = k.next().unwrap().parse().unwrap();
-        y = k.next().unwrap().parse().unwrap();
-        for i in matrix.iter_mut().skip(s_x).take(x) {
-            for j in i.iter_mut().skip(s_y).take(y) {
-                if j.value == 0 {
-                    j.value = 1;
-                    j.owners.push(id.to_string());
-                } else if j.value == 1 {
-                    count += 1;
-                    j.owners.push(id.to_string());
-                    j.value = 2;
-                } else {
-                    j.owners.push(id.to_string());
+        let x: i64 = k.next().unwrap().parse().unwrap();
+        let y: i64 = k.next().unwrap().parse().unwrap();
+        for i in s_x..s_x + x {
+            for j in s_y..s_y + y {
+                let square = fabric.entry(P2::new(i, j)).or_default();
+                if square.claims < 2 {
+                    square.claims += 1;
                 }
+                square.owners.push(id.to_string());
             }
         }
     }
+    (fabric, ids)
+}
+
+fn part1(fabric: &HashMap<P2, Square>) -> usize {
+    fabric.values().filter(|square| square.claims > 1).count()
+}
 
-    for i in matrix.iter_mut() {
-        for j in i {
-            if j.value > 1 {
-                for o in j.owners.iter() {
-                    if ids.contains(o) {
-                        ids.remove(o);
-                    }
+fn part2(fabric: &HashMap<P2, Square>, mut ids: HashSet<String>) -> usize {
+    for square in fabric.values() {
+        if square.claims > 1 {
+            for o in square.owners.iter() {
+                if ids.contains(o) {
+                    ids.remove(o);
                 }
             }
         }
     }
-    println!("overlap {}", count);
-    println!("non-conflicted {:?}", ids);
+    // only a single claim is expected to be left without conflicts
+    ids.iter()
+        .next()
+        .unwrap()
+        .trim_start_matches('#')
+        .parse::<usize>()
+        .unwrap()
 }
@@ -2,7 +2,8 @@ use std::collections::HashMap;
 
 use chrono::{Datelike, Duration, NaiveDateTime, Timelike};
 
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
 #[derive(Debug)]
 struct Sleep {
@@ -47,15 +48,13 @@ impl Sleep {
 }
 #[derive(Debug)]
 struct GuardSleep {
-    id: u16,
     total_sleep_time: u64,
     sleep_data: Vec<u16>,
 }
 
 impl GuardSleep {
-    fn new(id: u16) -> Self {
+    fn new() -> Self {
         Self {
-            id,
             total_sleep_time: 0,
             sleep_data: vec![0; 60],
         }
@@ -71,8 +70,15 @@ impl GuardSleep {
     }
 }
 
-pub fn run() {
-    let file_contents = get_puzzle_input(2018, 4);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let file_contents = timer.input(|| get_puzzle_input(2018, 4))?;
+    let guard_sleep_data = timer.parse(|| parse(&file_contents));
+    let p1 = timer.part1(|| part_1(&guard_sleep_data));
+    let p2 = timer.part2(|| part_2(&guard_sleep_data));
+    Ok(Solution::new(p1, p2))
+}
+
+fn parse(file_contents: &str) -> HashMap<u16, GuardSleep> {
     let mut sleep_data: HashMap<(i32, u32, u32), Sleep> = HashMap::new();
     for line in file_contents.lines() {
         let iter = &mut line[1..].split("] ");
@@ -106,16 +112,10 @@ pub fn run() {
         v.calculate_sleep();
         let guard = guard_sleep_data
             .entry(v.id.unwrap())
-            .or_insert_with(|| GuardSleep::new(v.id.unwrap()));
+            .or_insert_with(GuardSleep::new);
         guard.add_new_data(&v.sleep_time);
     }
-
-    for v in guard_sleep_data.values() {
-        println!("{} {} {:?}", v.id, v.total_sleep_time, v.sleep_data);
-    }
-
-    part_1(&guard_sleep_data);
-    part_2(&guard_sleep_data);
+    guard_sleep_data
 }
 
 fn time_adjust(date: NaiveDateTime) -> NaiveDateTime {
@@ -134,7 +134,7 @@ fn get_time(date: &NaiveDateTime) -> u32 {
     date.minute()
 }
 
-fn part_1(guard_sleep_data: &HashMap<u16, GuardSleep>) {
+fn part_1(guard_sleep_data: &HashMap<u16, GuardSleep>) -> u64 {
     let mut max_sleep = 0;
     let mut sleepy: (u16, u16) = (0, 0);
     for (id, v) in guard_sleep_data {
@@ -151,12 +151,10 @@ fn part_1(guard_sleep_data: &HashMap<u16, GuardSleep>) {
             sleepy = (*id, most_sleepy_at);
         }
     }
-    dbg!(sleepy);
-    let result1 = sleepy.0 as u64 * sleepy.1 as u64;
-    println!("{}", result1);
+    sleepy.0 as u64 * sleepy.1 as u64
 }
 
-fn part_2(guard_sleep_data: &HashMap<u16, GuardSleep>) {
+fn part_2(guard_sleep_data: &HashMap<u16, GuardSleep>) -> u64 {
     let mut max_sleepy_time = 0;
     let mut max_sleepy_id = 0;
     let mut max_sleepy_when = 0;
@@ -173,7 +171,5 @@ fn part_2(guard_sleep_data: &HashMap<u16, GuardSleep>) {
             max_sleepy_when = guard_max.0;
         }
     }
-    dbg!(max_sleepy_id, max_sleepy_when);
-    let result2 = max_sleepy_when as u64 * max_sleepy_id as u64;
-    println!("{}", result2);
+    max_sleepy_when as u64 * max_sleepy_id as u64
 }
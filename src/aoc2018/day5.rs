@@ -1,25 +1,34 @@
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = get_puzzle_input(2018, 5);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 5))?;
+    let p1 = timer.part1(|| react(input.chars()));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
+}
+
+fn part2(input: &str) -> usize {
     let mut v = Vec::new();
     for c in b'a'..=b'z' {
-        let mut output = "".to_string();
         let input_modified = input
             .chars()
             .filter(|&d| char::from(c) != d && char::from(c).to_ascii_uppercase() != d);
-        for i in input_modified {
-            if i != output.chars().last().unwrap_or('0')
-                && i.to_ascii_uppercase()
-                    == output.chars().last().unwrap_or('0').to_ascii_uppercase()
-            {
-                output.pop();
-            } else {
-                output.push(i);
-            }
+        v.push(react(input_modified));
+    }
+    *v.iter().min().unwrap()
+}
+
+fn react(polymer: impl Iterator<Item = char>) -> usize {
+    let mut output = "".to_string();
+    for i in polymer {
+        if i != output.chars().last().unwrap_or('0')
+            && i.eq_ignore_ascii_case(&output.chars().last().unwrap_or('0'))
+        {
+            output.pop();
+        } else {
+            output.push(i);
         }
-        v.push(output.len());
-        println!("p1 {}", output.len());
     }
-    println!("p2 {}", v.iter().min().unwrap());
+    output.len()
 }
@@ -1,9 +1,18 @@
 use std::{cmp::Ordering, collections::HashMap};
 
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = get_puzzle_input(2018, 6);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 6))?;
+    let (mut board, locs) = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&mut board, &locs));
+    let p2 = timer.part2(|| part2(&board, &locs));
+    Ok(Solution::new(p1, p2))
+}
+
+/// Returns an empty board covering all locations and the locations shifted onto it
+fn parse(input: &str) -> (Vec<Vec<String>>, Vec<(u32, u32)>) {
     // let input = include_str!("../input.txt");
     let mut locs = vec![];
     for line in input.lines() {
@@ -16,8 +25,6 @@ pub fn run() {
     let x_max = locs.iter().max_by_key(|&(x, _)| x).unwrap().0;
     let y_min = locs.iter().min_by_key(|&(_, y)| y).unwrap().1;
     let y_max = locs.iter().max_by_key(|&(_, y)| y).unwrap().1;
-    println!("{} {}", x_min, y_min);
-    println!("{} {}", x_max, y_max);
     let x_len = x_max - x_min + 1;
     let y_len = y_max - y_min + 1;
     for (i, j) in &mut locs {
@@ -25,16 +32,15 @@ pub fn run() {
         *j -= y_min;
     }
 
-    let mut board = vec![vec![".".to_string(); y_len as usize]; x_len as usize];
-    part1(&mut board, &locs);
-    part2(&board, &locs);
+    let board = vec![vec![".".to_string(); y_len as usize]; x_len as usize];
+    (board, locs)
 }
 
 pub fn calculate_man_distance(p1: (u32, u32), p2: (u32, u32)) -> u64 {
     ((p1.0 as i64 - p2.0 as i64).abs() + (p1.1 as i64 - p2.1 as i64).abs()) as u64
 }
 
-pub fn part1(board: &mut [Vec<String>], locs: &[(u32, u32)]) {
+pub fn part1(board: &mut [Vec<String>], locs: &[(u32, u32)]) -> u64 {
     for (i, v) in board.iter_mut().enumerate() {
         for (j, s) in v.iter_mut().enumerate() {
             let mut min_distance = u64::MAX;
@@ -83,16 +89,16 @@ pub fn part1(board: &mut [Vec<String>], locs: &[(u32, u32)]) {
         .iter()
         .max_by_key(|&(_, v)| if let Some(v) = v { *v } else { 0 })
         .unwrap();
-    println!("p1 {}", max.1.unwrap());
+    max.1.unwrap()
 }
 
-pub fn part2(board: &[Vec<String>], locs: &[(u32, u32)]) {
+pub fn part2(board: &[Vec<String>], locs: &[(u32, u32)]) -> usize {
     let max_allowed = 10000;
     let mut count = 0;
     for (i, v) in board.iter().enumerate() {
         for (j, _) in v.iter().enumerate() {
             let mut total = 0;
-            for (_, l) in locs.iter().enumerate() {
+            for l in locs.iter() {
                 let d = calculate_man_distance((i as u32, j as u32), *l);
                 total += d;
             }
@@ -101,5 +107,5 @@ pub fn part2(board: &[Vec<String>], locs: &[(u32, u32)]) {
             }
         }
     }
-    println!("p2 {}", count);
+    count
 }
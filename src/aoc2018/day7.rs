@@ -1,9 +1,17 @@
 use std::{collections::HashMap, collections::HashSet};
 
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = get_puzzle_input(2018, 7);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 7))?;
+    let mut task_precedence = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part_1(&mut task_precedence.clone()));
+    let p2 = timer.part2(|| part_2(&mut task_precedence));
+    Ok(Solution::new(p1, p2))
+}
+
+fn parse(input: &str) -> HashMap<u8, HashSet<u8>> {
     let mut task_precedence = HashMap::new();
     for line in input.lines() {
         let line = line
@@ -21,11 +29,10 @@ pub fn run() {
         let _ = task_precedence.entry(pre).or_insert_with(HashSet::new); //also push for pre task as well if a task has never have pre
     }
     // println!("{:?}", task_precedence);
-    part_2(&mut task_precedence);
+    task_precedence
 }
 
-#[allow(dead_code)]
-fn part_1(task_precedence: &mut HashMap<u8, HashSet<u8>>) {
+fn part_1(task_precedence: &mut HashMap<u8, HashSet<u8>>) -> String {
     let mut result = vec![];
     let n = task_precedence.len();
     while result.len() != n {
@@ -41,11 +48,10 @@ fn part_1(task_precedence: &mut HashMap<u8, HashSet<u8>>) {
             pre.remove(&next);
         });
     }
-    let result = String::from_utf8(result).unwrap();
-    println!("{}", result);
+    String::from_utf8(result).unwrap()
 }
 
-fn part_2(task_precedence: &mut HashMap<u8, HashSet<u8>>) {
+fn part_2(task_precedence: &mut HashMap<u8, HashSet<u8>>) -> usize {
     let mut result = vec![];
     let n = task_precedence.len();
     let nb_total_workers = 5;
@@ -56,7 +62,7 @@ fn part_2(task_precedence: &mut HashMap<u8, HashSet<u8>>) {
             .iter()
             .filter(|&(_, pre)| pre.is_empty())
             .map(|(&t, _)| t)
-            .next()
+            .min()
         {
             result.push(next_task);
             task_precedence.remove(&next_task);
@@ -103,7 +109,7 @@ fn part_2(task_precedence: &mut HashMap<u8, HashSet<u8>>) {
         // println!("precedence {:?}", task_precedence);
         // println!("workers {:?}", workers);
     }
-    println!("{:?}", workers[0].len());
+    workers[0].len()
 }
 
 fn get_len(task: u8) -> u8 {
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
 #[derive(Debug, Clone)]
 struct Node {
@@ -39,17 +40,20 @@ enum InputType {
     ReadMetadata,
 }
 
-pub fn run() {
-    let input = get_puzzle_input(2018, 8);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 8))?;
     // let input = read_file("toy.txt");
+    let root = timer.parse(|| parse(&input));
+    let part1_sum = timer.part1(|| count_metadata(&root));
+    let part2_sum = timer.part2(|| count_ref_metadata(&root));
+    Ok(Solution::new(part1_sum, part2_sum))
+}
+
+fn parse(input: &str) -> Node {
     let mut it = input.split_ascii_whitespace();
     let mut init_node = Node::new();
-    let current_node = &mut init_node;
-    process_node(current_node, &mut it);
-    let part1_sum = count_metadata(current_node);
-    println!("p1 {}", part1_sum);
-    let part2_sum = count_ref_metadata(current_node);
-    println!("p2 {}", part2_sum);
+    process_node(&mut init_node, &mut it);
+    init_node
 }
 
 fn process_node<'a>(node: &mut Node, it: &mut impl Iterator<Item = &'a str>) {
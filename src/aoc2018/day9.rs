@@ -1,16 +1,24 @@
 use std::collections::VecDeque;
 
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = get_puzzle_input(2018, 9);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2018, 9))?;
+    let (nb_players, last_point) = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| solve(nb_players, last_point));
+    let p2 = timer.part2(|| solve(nb_players, last_point * 100));
+    Ok(Solution::new(p1, p2))
+}
+
+fn parse(input: &str) -> (usize, usize) {
     let mut it = input.split_ascii_whitespace();
     let nb_players = it.next().unwrap().parse::<usize>().unwrap();
     let last_point = it.nth(5).unwrap().parse::<usize>().unwrap();
-    solve(nb_players, last_point * 100);
+    (nb_players, last_point)
 }
 
-fn solve(nb_players: usize, last_point: usize) {
+fn solve(nb_players: usize, last_point: usize) -> usize {
     let mut points = vec![0; nb_players];
     let mut board = VecDeque::new();
     board.push_back(0);
@@ -24,5 +32,5 @@ fn solve(nb_players: usize, last_point: usize) {
             board.push_back(s);
         }
     }
-    println!("{}", points.iter().max().unwrap());
+    *points.iter().max().unwrap()
 }
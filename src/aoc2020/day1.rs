@@ -1,18 +1,22 @@
 use std::collections::HashSet;
 
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = get_puzzle_input(2020, 1);
-    let mut nums = vec![];
-    input
-        .lines()
-        .for_each(|line| nums.push(line.parse().unwrap()));
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 1))?;
+    let mut nums = timer.parse(|| parse(&input));
     let target = 2020u32;
-    let (x, y) = two_sum(&nums, target);
-    println!("p1 {}", x * y);
-    let v = three_sum(&mut nums, target);
-    println!("p2 {}", v.iter().product::<u32>());
+    let p1 = timer.part1(|| {
+        let (x, y) = two_sum(&nums, target);
+        x * y
+    });
+    let p2 = timer.part2(|| three_sum(&mut nums, target).iter().product::<u32>());
+    Ok(Solution::new(p1, p2))
+}
+
+fn parse(input: &str) -> Vec<u32> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
 }
 
 fn two_sum(nums: &[u32], target: u32) -> (u32, u32) {
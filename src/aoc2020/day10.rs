@@ -1,9 +1,26 @@
 use std::collections::HashMap;
 
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = get_puzzle_input(2020, 10);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 10))?;
+    let v = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&v));
+    let p2 = timer.part2(|| part2(&v));
+    Ok(Solution::new(p1, p2))
+}
+
+/// Counts the arrangements with a memoized recursion
+pub fn run_recursive(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 10))?;
+    let v = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&v));
+    let p2 = timer.part2(|| part2_recursive(&v));
+    Ok(Solution::new(p1, p2))
+}
+
+fn parse(input: &str) -> Vec<u16> {
     let mut v = input
         .lines()
         .map(|l| l.parse::<u16>().unwrap())
@@ -11,10 +28,7 @@ pub fn run() {
     v.push(0);
     v.push(v.iter().max().unwrap() + 3);
     v.sort_unstable();
-    let p1 = part1(&v);
-    println!("{}", p1);
-    let p2 = part2(&v);
-    println!("{}", p2);
+    v
 }
 
 fn part1(v: &[u16]) -> u16 {
@@ -45,13 +59,11 @@ fn part2(v: &[u16]) -> usize {
     reach_vec[reach_vec.len() - 1]
 }
 
-#[allow(dead_code)]
 fn part2_recursive(v: &[u16]) -> usize {
     let mut map = HashMap::new();
     number_of_arrangements(v, 0, &mut map)
 }
 
-#[allow(dead_code)]
 fn number_of_arrangements(v: &[u16], index: usize, map: &mut HashMap<usize, usize>) -> usize {
     if index == v.len() - 1 {
         return 1;
@@ -1,69 +1,36 @@
-use std::collections::HashMap;
-
-use crate::util::get_puzzle_input;
-
-static NEIGHBOUR_INDEX: [(i8, i8); 8] = [
-    (1, 0),
-    (-1, 0),
-    (0, 1),
-    (0, -1),
-    (1, 1),
-    (1, -1),
-    (-1, 1),
-    (-1, -1),
-];
+use crate::grid::{Grid, NEIGHBOURS_8};
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
 #[derive(Debug, Clone)]
 struct Area {
-    seats: HashMap<Point, Seat>,
-    _x_len: isize,
-    _y_len: isize,
+    seats: Grid<Seat>,
     round: usize,
 }
 
 impl Area {
     fn new(input: &str) -> Self {
-        let mut seats = HashMap::new();
-        for (i, line) in input.lines().enumerate() {
-            for (j, c) in line.chars().enumerate() {
-                seats.insert(Point(i as isize, j as isize), Seat::convert_from_char(c));
-            }
-        }
-        let x_len = seats.keys().max_by_key(|&p| p.0).unwrap().0 + 1;
-        let y_len = seats.keys().max_by_key(|&p| p.1).unwrap().1 + 1;
         Self {
-            seats,
-            _x_len: x_len,
-            _y_len: y_len,
+            seats: Grid::parse(input, Seat::convert_from_char),
             round: 0,
         }
     }
 
-    fn get_neighbours(&self, p: &Point) -> Vec<Seat> {
-        let mut v = vec![];
-        NEIGHBOUR_INDEX.iter().for_each(|&(i, j)| {
-            if let Some(s) = self.seats.get(&Point(p.0 + i as isize, p.1 + j as isize)) {
-                v.push(*s);
-            }
-        });
-        v
+    fn get_neighbours(&self, p: (usize, usize)) -> Vec<Seat> {
+        self.seats.neighbours8(p).map(|n| self.seats[n]).collect()
     }
 
-    fn get_first_seat_each_direction(&self, p: &Point) -> Vec<Seat> {
+    fn get_first_seat_each_direction(&self, p: (usize, usize)) -> Vec<Seat> {
         let mut v = vec![];
-        NEIGHBOUR_INDEX.iter().for_each(|&(i, j)| {
-            let mut x = p.0 + i as isize;
-            let mut y = p.1 + j as isize;
-            while let Some(p) = self.seats.get(&Point(x, y)) {
-                match p {
+        NEIGHBOURS_8.iter().for_each(|&delta| {
+            let mut current = self.seats.step(p, delta);
+            while let Some(n) = current {
+                match self.seats[n] {
                     Seat::Empty | Seat::Occupied => {
-                        v.push(*p);
+                        v.push(self.seats[n]);
                         break;
                     }
-                    _ => {
-                        x += i as isize;
-                        y += j as isize;
-                    }
+                    _ => current = self.seats.step(n, delta),
                 }
             }
         });
@@ -71,12 +38,11 @@ impl Area {
         v
     }
 
-    fn get_nb_occupied_neighbours(&self, p: &Point, solve_type: SolveType) -> usize {
-        let v;
-        match solve_type {
-            SolveType::P1 => v = self.get_neighbours(p),
-            SolveType::P2 => v = self.get_first_seat_each_direction(p),
-        }
+    fn get_nb_occupied_neighbours(&self, p: (usize, usize), solve_type: SolveType) -> usize {
+        let v = match solve_type {
+            SolveType::P1 => self.get_neighbours(p),
+            SolveType::P2 => self.get_first_seat_each_direction(p),
+        };
         v.into_iter().filter(|&s| s == Seat::Occupied).count()
     }
 
@@ -84,17 +50,17 @@ impl Area {
         self.round += 1;
         let mut changed_flag = false;
         let mut clone_seats = self.seats.clone();
-        for (&p, &seat) in self.seats.iter() {
+        for (p, &seat) in self.seats.iter() {
             match seat {
-                Seat::Empty if self.get_nb_occupied_neighbours(&p, kind.solve_type) == 0 => {
-                    clone_seats.entry(p).and_modify(|x| *x = Seat::Occupied);
+                Seat::Empty if self.get_nb_occupied_neighbours(p, kind.solve_type) == 0 => {
+                    clone_seats[p] = Seat::Occupied;
                     changed_flag = true;
                 }
                 Seat::Occupied
-                    if self.get_nb_occupied_neighbours(&p, kind.solve_type)
+                    if self.get_nb_occupied_neighbours(p, kind.solve_type)
                         >= kind.occupancy_limit =>
                 {
-                    clone_seats.entry(p).and_modify(|x| *x = Seat::Empty);
+                    clone_seats[p] = Seat::Empty;
                     changed_flag = true;
                 }
                 _ => (),
@@ -117,26 +83,19 @@ impl Area {
 
     fn get_total_nb_occupied(&self) -> usize {
         self.seats
-            .values()
+            .cells()
+            .iter()
             .filter(|&s| *s == Seat::Occupied)
             .count()
     }
     #[cfg(debug_assertions)]
     fn print_area(&self) {
         println!("___");
-        for i in 0..self._x_len {
-            for j in 0..self._y_len {
-                print!("{}", self.seats.get(&Point(i, j)).unwrap());
-            }
-            println!();
-        }
+        print!("{}", self.seats);
         println!("___");
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-struct Point(isize, isize);
-
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum Seat {
     Floor,
@@ -175,14 +134,13 @@ impl Seat {
     }
 }
 
-pub fn run() {
-    let input = get_puzzle_input(2020, 11);
-    let mut area = Area::new(&input);
-    let p1 = part1(&mut area);
-    println!("p1 {} after {} rounds", p1, area.round);
-    let mut area = Area::new(&input);
-    let p2 = part2(&mut area);
-    println!("p2 {} after {} rounds", p2, area.round);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 11))?;
+    let mut area = timer.parse(|| Area::new(&input));
+    let p1 = timer.part1(|| part1(&mut area));
+    let mut area = timer.parse(|| Area::new(&input));
+    let p2 = timer.part2(|| part2(&mut area));
+    Ok(Solution::new(p1, p2))
 }
 
 fn part1(area: &mut Area) -> usize {
@@ -209,7 +167,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_p1() {
-        let input = crate::util::read_file("inputs/2020_11_test.in");
+        let input = crate::util::read_file("inputs/2020_11_test.in").unwrap();
         let mut area = Area::new(&input);
         let p1 = part1(&mut area);
         assert_eq!(37, p1);
@@ -217,7 +175,7 @@ mod tests {
 
     #[test]
     fn test_p2() {
-        let input = crate::util::read_file("inputs/2020_11_test.in");
+        let input = crate::util::read_file("inputs/2020_11_test.in").unwrap();
         let mut area = Area::new(&input);
         let p1 = part2(&mut area);
         assert_eq!(26, p1);
@@ -1,18 +1,19 @@
 use lazy_static::lazy_static;
 
+use crate::util::Error;
 use regex::Regex;
+use year_lib::{Solution, Timer};
 
 lazy_static! {
     static ref REG: Regex = Regex::new(r"x|\d+").unwrap();
 }
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 13);
-    let (earliest, mut buses) = parse(&input);
-    let p1 = part1(&buses, earliest);
-    println!("p1 {}", p1);
-    let p2 = part2(&mut buses);
-    println!("p2 {}", p2);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 13))?;
+    let (earliest, mut buses) = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&buses, earliest));
+    let p2 = timer.part2(|| part2(&mut buses));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> (usize, Vec<BusWait>) {
@@ -82,7 +83,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2020_13_test.in");
+        let input = crate::util::read_file("inputs/2020_13_test.in").unwrap();
         let (e, b) = parse(&input);
         let p1 = part1(&b, e);
         assert_eq!(295, p1);
@@ -90,7 +91,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2020_13_test.in");
+        let input = crate::util::read_file("inputs/2020_13_test.in").unwrap();
         let (_e, mut b) = parse(&input);
         let p2 = part2(&mut b);
         assert_eq!(1068781, p2);
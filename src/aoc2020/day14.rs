@@ -1,36 +1,34 @@
 use std::collections::HashMap;
 
-use lazy_static::lazy_static;
-
-use regex::Regex;
-
-lazy_static! {
-    static ref MEM_RE: Regex = Regex::new(r"^mem\[(\d+)\]\s=\s(\d+)").unwrap();
-}
-
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 14);
-    let p1 = part1(&input);
-    println!("p1 {}", p1);
-    let p2 = part2(&input);
-    println!("p2 {}", p2);
+use year_lib::{Solution, Timer};
+
+use crate::{
+    parse::{ParseError, Span},
+    util::Error,
+};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 14))?;
+    let p1 = timer.part1(|| part1(&input))?;
+    let p2 = timer.part2(|| part2(&input))?;
+    Ok(Solution::new(p1, p2))
 }
 
-fn part1(input: &str) -> usize {
+fn part1(input: &str) -> Result<usize, ParseError> {
     get_sum(input, MaskType::P1)
 }
 
-fn part2(input: &str) -> usize {
+fn part2(input: &str) -> Result<usize, ParseError> {
     get_sum(input, MaskType::P2)
 }
 
-fn get_sum(input: &str, mask_type: MaskType) -> usize {
-    let mut v = parse(input);
+fn get_sum(input: &str, mask_type: MaskType) -> Result<usize, ParseError> {
+    let mut v = parse(input)?;
     match mask_type {
         MaskType::P1 => v.iter_mut().for_each(|x| x.apply_mask()),
         MaskType::P2 => v.iter_mut().for_each(|x| x.apply_mask_v2()),
     }
-    v.into_iter()
+    Ok(v.into_iter()
         .fold(HashMap::new(), |mut acc, x| {
             x.calculated_mem_value_pairs.into_iter().for_each(|(k, v)| {
                 let _ = acc.insert(k, v);
@@ -38,27 +36,26 @@ fn get_sum(input: &str, mask_type: MaskType) -> usize {
             acc
         })
         .values()
-        .sum::<usize>()
+        .sum::<usize>())
 }
 
-fn parse(input: &str) -> Vec<Mask<'_>> {
-    input
-        .split("mask = ")
-        .skip(1)
-        .map(|group| {
-            let mut lines = group.lines();
-            let mask_str = lines.next().unwrap();
-            let v = lines
-                .map(|line| {
-                    let capture = MEM_RE.captures(line).unwrap();
-                    let mem = capture.get(1).unwrap().as_str().parse::<usize>().unwrap();
-                    let value = capture.get(2).unwrap().as_str().parse::<usize>().unwrap();
-                    (format!("{:b}", mem), format!("{:b}", value))
-                })
-                .collect::<Vec<_>>();
-            Mask::new(mask_str, v)
-        })
-        .collect::<Vec<_>>()
+fn parse(input: &str) -> Result<Vec<Mask<'_>>, ParseError> {
+    let mut masks = Vec::<Mask>::new();
+    for line in Span::new(input).lines() {
+        if let Ok(mask_str) = line.strip_prefix("mask = ") {
+            masks.push(Mask::new(mask_str.as_str(), Vec::new()));
+            continue;
+        }
+        let [mem, value] = line.scan("mem[{}] = {}")?;
+        let mask = masks
+            .last_mut()
+            .ok_or_else(|| line.error("expected a mask before the first write"))?;
+        mask.mem_value_pairs.push((
+            format!("{:b}", mem.parse::<usize>()?),
+            format!("{:b}", value.parse::<usize>()?),
+        ));
+    }
+    Ok(masks)
 }
 
 enum MaskType {
@@ -144,15 +141,15 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2020_14_test_1.in");
-        let p1 = part1(&input);
+        let input = crate::util::read_file("inputs/2020_14_test_1.in").unwrap();
+        let p1 = part1(&input).unwrap();
         assert_eq!(165, p1);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2020_14_test_2.in");
-        let p2 = part2(&input);
+        let input = crate::util::read_file("inputs/2020_14_test_2.in").unwrap();
+        let p2 = part2(&input).unwrap();
         assert_eq!(208, p2);
     }
 }
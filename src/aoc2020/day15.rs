@@ -1,12 +1,22 @@
+use crate::util::Error;
 use std::collections::HashMap;
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 15);
-    let vec = parse(&input);
-    let p1 = part1(&vec);
-    println!("p1 {}", p1);
-    let p2 = part2(&vec);
-    println!("p2 {}", p2);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 15))?;
+    let vec = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&vec));
+    let p2 = timer.part2(|| part2(&vec));
+    Ok(Solution::new(p1, p2))
+}
+
+/// Keeps the last turns of the numbers in a map for both parts
+pub fn run_map(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 15))?;
+    let vec = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| calculate_with_map(&vec, 2020));
+    let p2 = timer.part2(|| calculate_with_map(&vec, 30000000));
+    Ok(Solution::new(p1, p2))
 }
 
 #[inline]
@@ -39,7 +49,7 @@ fn calculate_with_map(vec: &[usize], n: usize) -> usize {
                 *x = i;
             })
             .or_insert(i);
-        let new_key = i.saturating_sub(old_position); 
+        let new_key = i.saturating_sub(old_position);
         key = new_key; // set new key
     }
     key
@@ -66,6 +76,7 @@ mod tests {
         let vec = vec![0, 3, 6];
         let p1 = part1(&vec);
         assert_eq!(436, p1);
+        assert_eq!(436, calculate_with_vec(&vec, 2020));
     }
 
     #[test]
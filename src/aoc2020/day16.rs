@@ -5,18 +5,19 @@ use std::{
 
 use lazy_static::lazy_static;
 
+use crate::util::Error;
 use regex::Regex;
+use year_lib::{Solution, Timer};
 
 lazy_static! {
     static ref RULES_RE: Regex = Regex::new(r"((\d+)-(\d+))").unwrap();
 }
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 16);
-    let p1 = part1(&input);
-    println!("p1 {}", p1);
-    let p2 = part2(&input);
-    println!("p2 {}", p2);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 16))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 #[inline]
@@ -131,7 +132,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2020_16_test.in");
+        let input = crate::util::read_file("inputs/2020_16_test.in").unwrap();
         let p1 = part1(&input);
         assert_eq!(p1, 71);
     }
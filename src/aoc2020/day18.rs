@@ -1,9 +1,11 @@
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 18);
-    let p1 = part1(&input);
-    println!("p1 {}", p1);
-    let p2 = part2(&input);
-    println!("p2 {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 18))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 struct ArithmeticState<'a> {
@@ -36,7 +38,7 @@ impl<'a> ArithmeticState<'a> {
                         self.parse_status = ParseStatus::Number(x, i + 1);
                     }
                 },
-                c if c == '+' => match self.parse_status {
+                '+' => match self.parse_status {
                     ParseStatus::Operator => {
                         self.current_operator = Operator::Sum;
                     }
@@ -47,7 +49,7 @@ impl<'a> ArithmeticState<'a> {
                         self.current_operator = Operator::Sum;
                     }
                 },
-                c if c == '*' => match self.parse_status {
+                '*' => match self.parse_status {
                     ParseStatus::Operator => {
                         self.current_operator = Operator::Multiply;
                     }
@@ -58,13 +60,13 @@ impl<'a> ArithmeticState<'a> {
                         self.current_operator = Operator::Multiply;
                     }
                 },
-                c if c == '(' => {
+                '(' => {
                     let mut sub_state = ArithmeticState::new(&self.formula[i + 1..]);
                     let (num, ind) = sub_state.calculate();
                     self.result = self.current_operator.apply(self.result, num);
                     i += ind + 1;
                 }
-                c if c == ')' => {
+                ')' => {
                     match self.parse_status {
                         ParseStatus::Operator => (),
                         ParseStatus::Number(x, y) => {
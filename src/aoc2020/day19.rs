@@ -1,20 +1,17 @@
 use std::collections::HashMap;
 
-use lazy_static::lazy_static;
+use year_lib::{Solution, Timer};
 
-use regex::Regex;
+use crate::{
+    parse::{ParseError, Span},
+    util::Error,
+};
 
-lazy_static! {
-    static ref REG: Regex = Regex::new(r"\d+").unwrap();
-    static ref TERMINAL_REG: Regex = Regex::new(r"\w+").unwrap();
-}
-
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 19);
-    let p1 = part1(&input);
-    println!("p1 {}", p1);
-    let p2 = part2(&input);
-    println!("p1 {}", p2);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 19))?;
+    let p1 = timer.part1(|| part1(&input))?;
+    let p2 = timer.part2(|| part2(&input))?;
+    Ok(Solution::new(p1, p2))
 }
 
 #[derive(Debug, Clone)]
@@ -24,47 +21,33 @@ pub enum Rule {
     Or(Vec<u8>, Vec<u8>),
 }
 
-fn parse(input: &str) -> (HashMap<u8, Rule>, &str) {
-    let mut it = input.split("\n\n");
-    let rules_str = it.next().unwrap();
-    let words_str = it.next().unwrap();
+fn parse(input: &str) -> Result<(HashMap<u8, Rule>, &str), ParseError> {
+    let [rules_str, words_str] = Span::new(input).sections()?;
     let mut map = HashMap::new();
     for r_line in rules_str.lines() {
-        let mut r_it = r_line.split(':');
-        let parent = r_it.next().unwrap().parse::<u8>().unwrap();
-        let children_str = r_it.next().unwrap();
-        if !children_str.contains('\"') {
-            let children_it = children_str.split('|');
-            let rules = children_it
-                .map(|child| {
-                    REG.captures_iter(child)
-                        .map(|x| x.get(0).unwrap().as_str().parse::<u8>().unwrap())
-                        .collect()
-                })
-                .collect::<Vec<Vec<_>>>();
-            if rules.len() == 1 {
-                map.insert(parent, Rule::One(rules[0].clone()));
-            } else {
-                map.insert(parent, Rule::Or(rules[0].clone(), rules[1].clone()));
-            }
+        let (parent, children) = r_line.split_once(":")?;
+        let children = children.trim();
+        let rule = if children.as_str().starts_with('"') {
+            let [terminal] = children.scan("\"{}\"")?;
+            Rule::Char(terminal.parse()?)
         } else {
-            let terminal_rules = TERMINAL_REG
-                .captures_iter(children_str)
-                .map(|x| x.get(0).unwrap().as_str().chars().next().unwrap())
-                .collect::<Vec<_>>();
-            debug_assert!(terminal_rules.len() == 1);
-            map.insert(parent, Rule::Char(terminal_rules[0]));
-        }
+            let rules = children
+                .split("|")
+                .map(|child| child.integers())
+                .collect::<Result<Vec<_>, _>>()?;
+            match <[_; 2]>::try_from(rules) {
+                Ok([rules_1, rules_2]) => Rule::Or(rules_1, rules_2),
+                Err(mut rules) if rules.len() == 1 => Rule::One(rules.remove(0)),
+                Err(_) => return Err(children.error("expected at most 2 alternatives")),
+            }
+        };
+        map.insert(parent.parse()?, rule);
     }
-    (map, words_str)
+    Ok((map, words_str.as_str()))
 }
 
 fn solve<'a>(word: &'a str, rule: u8, rules_map: &HashMap<u8, Rule>) -> Vec<&'a str> {
-    fn solve_inner<'a>(
-        word: &'a str,
-        rules: &[u8],
-        rules_map: &HashMap<u8, Rule>,
-    ) -> Vec<&'a str> {
+    fn solve_inner<'a>(word: &'a str, rules: &[u8], rules_map: &HashMap<u8, Rule>) -> Vec<&'a str> {
         let mut words = vec![word];
         for rule in rules {
             let mut new_words = Vec::new();
@@ -94,13 +77,13 @@ fn solve<'a>(word: &'a str, rule: u8, rules_map: &HashMap<u8, Rule>) -> Vec<&'a
     }
 }
 
-fn part1(input: &str) -> usize {
-    let (rules, words) = parse(input);
-    words
+fn part1(input: &str) -> Result<usize, ParseError> {
+    let (rules, words) = parse(input)?;
+    Ok(words
         .lines()
         .flat_map(|word| solve(word, 0, &rules))
         .filter(|&x| x.is_empty())
-        .count()
+        .count())
 }
 
 fn alter_input(input: &str) -> String {
@@ -109,14 +92,14 @@ fn alter_input(input: &str) -> String {
         .replace("11: 42 31", "11: 42 31 | 42 11 31")
 }
 
-fn part2(input: &str) -> usize {
+fn part2(input: &str) -> Result<usize, ParseError> {
     let altered_input = alter_input(input);
-    let (rules, words) = parse(&altered_input);
-    words
+    let (rules, words) = parse(&altered_input)?;
+    Ok(words
         .lines()
         .flat_map(|word| solve(word, 0, &rules))
         .filter(|&x| x.is_empty())
-        .count()
+        .count())
 }
 
 #[cfg(test)]
@@ -124,14 +107,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2020_19_test_1.in");
-        let p1 = part1(&input);
+        let input = crate::util::read_file("inputs/2020_19_test_1.in").unwrap();
+        let p1 = part1(&input).unwrap();
         assert_eq!(p1, 2);
     }
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2020_19_test_2.in");
-        let p2 = part2(&input);
+        let input = crate::util::read_file("inputs/2020_19_test_2.in").unwrap();
+        let p2 = part2(&input).unwrap();
         assert_eq!(p2, 12);
     }
 }
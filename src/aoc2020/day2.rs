@@ -1,31 +1,43 @@
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
 
 use lazy_static::lazy_static;
 use regex::Regex;
+use year_lib::{Solution, Timer};
 
 lazy_static! {
     static ref REG: Regex = Regex::new(r"^(\d+)-(\d+)\s+(\w+):\s+(\w+)").unwrap();
 }
 
-pub fn run() {
-    let input = get_puzzle_input(2020, 2);
-    let mut p1_count = 0;
-    let mut p2_count = 0;
-    input.lines().for_each(|line| {
-        let matches = REG.captures(line).unwrap();
-        let min = matches.get(1).unwrap().as_str().parse::<u8>().unwrap();
-        let max = matches.get(2).unwrap().as_str().parse::<u8>().unwrap();
-        let letter = matches.get(3).unwrap().as_str().parse::<char>().unwrap();
-        let input = matches.get(4).unwrap().as_str();
-        if p1_check_password(input, letter, min, max) {
-            p1_count += 1;
-        }
-        if p2_check_password(input, letter, min, max) {
-            p2_count += 1;
-        }
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 2))?;
+    let policies = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| {
+        policies
+            .iter()
+            .filter(|&&(min, max, letter, password)| p1_check_password(password, letter, min, max))
+            .count()
     });
-    println!("p1 {}", p1_count);
-    println!("p2 {}", p2_count);
+    let p2 = timer.part2(|| {
+        policies
+            .iter()
+            .filter(|&&(min, max, letter, password)| p2_check_password(password, letter, min, max))
+            .count()
+    });
+    Ok(Solution::new(p1, p2))
+}
+
+fn parse(input: &str) -> Vec<(u8, u8, char, &str)> {
+    input
+        .lines()
+        .map(|line| {
+            let matches = REG.captures(line).unwrap();
+            let min = matches.get(1).unwrap().as_str().parse::<u8>().unwrap();
+            let max = matches.get(2).unwrap().as_str().parse::<u8>().unwrap();
+            let letter = matches.get(3).unwrap().as_str().parse::<char>().unwrap();
+            let password = matches.get(4).unwrap().as_str();
+            (min, max, letter, password)
+        })
+        .collect()
 }
 
 fn p1_check_password(input: &str, letter: char, min: u8, max: u8) -> bool {
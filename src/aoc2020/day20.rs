@@ -1,4 +1,6 @@
+use crate::util::Error;
 use std::collections::{HashMap, HashSet};
+use year_lib::{Solution, Timer};
 
 static MONSTER_PATTERN: [(u8, u8); 15] = [
     (0, 18),
@@ -21,14 +23,13 @@ static MONSTER_PATTERN: [(u8, u8); 15] = [
 static MONSTER_X_MAX: usize = 2;
 static MONSTER_Y_MAX: usize = 19;
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 20);
-    let tiles = parse(&input);
-    let whole_image = WholeImage::construct(tiles);
-    let p1 = part1(&whole_image);
-    println!("p1 {}", p1);
-    let p1 = part2(&whole_image);
-    println!("p1 {}", p1);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 20))?;
+    let tiles = timer.parse(|| parse(&input));
+    let whole_image = timer.parse(|| WholeImage::construct(tiles));
+    let p1 = timer.part1(|| part1(&whole_image));
+    let p2 = timer.part2(|| part2(&whole_image));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> Vec<Tile> {
@@ -113,7 +114,6 @@ impl WholeImage {
             tiles: &[Tile],
             all_v: &[Tile],
             seen: &mut HashSet<u16>,
-            len: u8,
         ) -> bool {
             if tiles.len() != seen.len() {
                 for t in all_v {
@@ -131,9 +131,8 @@ impl WholeImage {
                                 // image.get_mut(&(x, y)).unwrap().neighbourhood.up = true;
                                 image.insert((x, y + 1), t.clone());
                                 let new_y_max = y_max.max(y + 1);
-                                if solve(
-                                    x_min, x_max, y_min, new_y_max, image, tiles, all_v, seen, len,
-                                ) {
+                                if solve(x_min, x_max, y_min, new_y_max, image, tiles, all_v, seen)
+                                {
                                     return true;
                                 }
                                 //revert
@@ -148,9 +147,8 @@ impl WholeImage {
                                 // image.get_mut(&(x, y)).unwrap().neighbourhood.down = true;
                                 image.insert((x, y - 1), t.clone());
                                 let new_y_min = y_min.min(y - 1);
-                                if solve(
-                                    x_min, x_max, new_y_min, y_max, image, tiles, all_v, seen, len,
-                                ) {
+                                if solve(x_min, x_max, new_y_min, y_max, image, tiles, all_v, seen)
+                                {
                                     return true;
                                 }
                                 //revert
@@ -165,9 +163,8 @@ impl WholeImage {
                                 // image.get_mut(&(x, y)).unwrap().neighbourhood.left = true;
                                 image.insert((x - 1, y), t.clone());
                                 let new_x_min = x_min.min(x - 1);
-                                if solve(
-                                    new_x_min, x_max, y_min, y_max, image, tiles, all_v, seen, len,
-                                ) {
+                                if solve(new_x_min, x_max, y_min, y_max, image, tiles, all_v, seen)
+                                {
                                     return true;
                                 }
                                 //revert
@@ -182,9 +179,8 @@ impl WholeImage {
                                 // image.get_mut(&(x, y)).unwrap().neighbourhood.right = true;
                                 image.insert((x + 1, y), t.clone());
                                 let new_x_max = x_max.max(x + 1);
-                                if solve(
-                                    x_min, new_x_max, y_min, y_max, image, tiles, all_v, seen, len,
-                                ) {
+                                if solve(x_min, new_x_max, y_min, y_max, image, tiles, all_v, seen)
+                                {
                                     return true;
                                 }
                                 //revert
@@ -211,7 +207,7 @@ impl WholeImage {
         }
         seen.insert(id);
         image.insert((0, 0), tiles[0].clone());
-        let ret = solve(0, 0, 0, 0, &mut image, &tiles, &all_v, &mut seen, len);
+        let ret = solve(0, 0, 0, 0, &mut image, &tiles, &all_v, &mut seen);
         debug_assert!(ret);
         let x_min = image.iter().map(|(&(x, _y), _)| x).min().unwrap();
         let x_max = image.iter().map(|(&(x, _y), _)| x).max().unwrap();
@@ -436,6 +432,7 @@ impl TileHash {
         }
     }
 
+    #[allow(clippy::needless_range_loop)]
     fn calculate_hashes(image: &[Vec<bool>]) -> Self {
         let mut tile_hash = TileHash::new();
         for i in 0..image.len() {
@@ -453,7 +450,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2020_20_test.in");
+        let input = crate::util::read_file("inputs/2020_20_test.in").unwrap();
         let tiles = parse(&input);
         let whole_image = WholeImage::construct(tiles);
         let p1 = part1(&whole_image);
@@ -462,7 +459,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2020_20_test.in");
+        let input = crate::util::read_file("inputs/2020_20_test.in").unwrap();
         let tiles = parse(&input);
         let whole_image = WholeImage::construct(tiles);
         let p2 = part2(&whole_image);
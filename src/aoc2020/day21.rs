@@ -1,13 +1,14 @@
+use crate::util::Error;
 use std::collections::HashMap;
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 21);
-    let (mut pool, words) = parse(&input);
-    pool.solve();
-    let p1 = part1(&pool, &words);
-    println!("p1 {}", p1);
-    let p2 = part2(&pool);
-    println!("p2 {}", p2);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 21))?;
+    let (mut pool, words) = timer.parse(|| parse(&input));
+    timer.parse(|| pool.solve());
+    let p1 = timer.part1(|| part1(&pool, &words));
+    let p2 = timer.part2(|| part2(&pool));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> (AllergenPool<'_>, Vec<&str>) {
@@ -202,7 +203,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2020_21_test.in");
+        let input = crate::util::read_file("inputs/2020_21_test.in").unwrap();
         let (mut pool, words) = parse(&input);
         pool.solve();
         let p1 = part1(&pool, &words);
@@ -210,7 +211,7 @@ mod tests {
     }
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2020_21_test.in");
+        let input = crate::util::read_file("inputs/2020_21_test.in").unwrap();
         let (mut pool, _) = parse(&input);
         pool.solve();
         let p2 = part2(&pool);
@@ -1,15 +1,16 @@
+use crate::util::Error;
 use fnv::FnvHasher;
 use std::collections::{HashSet, VecDeque};
 use std::hash::{Hash, Hasher};
-
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 22);
-    let mut game = parse(&input);
-    let mut cloned = game.clone();
-    let part_1 = part1(&mut cloned);
-    println!("{}", part_1);
-    let part_2 = part2(&mut game);
-    println!("{}", part_2);
+use year_lib::{Solution, Timer};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 22))?;
+    let mut game = timer.parse(|| parse(&input));
+    let mut cloned = timer.parse(|| game.clone());
+    let part_1 = timer.part1(|| part1(&mut cloned));
+    let part_2 = timer.part2(|| part2(&mut game));
+    Ok(Solution::new(part_1, part_2))
 }
 
 fn parse(input: &str) -> GameBoard {
@@ -152,7 +153,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2020_22_test.in");
+        let input = crate::util::read_file("inputs/2020_22_test.in").unwrap();
         let mut game = parse(&input);
         let p1 = part1(&mut game);
         assert_eq!(p1, 306);
@@ -160,7 +161,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2020_22_test.in");
+        let input = crate::util::read_file("inputs/2020_22_test.in").unwrap();
         let mut game = parse(&input);
         let p2 = part2(&mut game);
         assert_eq!(p2, 291);
@@ -1,9 +1,11 @@
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 23);
-    let p1 = part1(&input);
-    println!("p1 {}", p1);
-    let p2 = part2(&input);
-    println!("p2 {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 23))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 fn part1(input: &str) -> String {
@@ -2,7 +2,9 @@ use std::collections::HashSet;
 
 use lazy_static::lazy_static;
 
+use crate::util::Error;
 use regex::Regex;
+use year_lib::{Solution, Timer};
 
 static NEIGHBOUR_INDEX: [(i8, i8); 6] = [(-1, -1), (-1, 1), (1, -1), (1, 1), (2, 0), (-2, 0)];
 
@@ -10,12 +12,11 @@ lazy_static! {
     static ref RE: Regex = Regex::new("[ns]?[ew]").unwrap();
 }
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 24);
-    let p1 = part1(&input);
-    println!("p1 {}", p1);
-    let p2 = part2(&input);
-    println!("p2 {}", p2);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 24))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 struct Tiles {
@@ -132,14 +133,14 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2020_24_test.in");
+        let input = crate::util::read_file("inputs/2020_24_test.in").unwrap();
         let p1 = part1(&input);
         assert_eq!(p1, 10);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2020_24_test.in");
+        let input = crate::util::read_file("inputs/2020_24_test.in").unwrap();
         let v = parse(&input);
         let mut tiles = Tiles::new(v);
         tiles.flip_by_day(1);
@@ -148,7 +149,7 @@ mod tests {
 
     #[test]
     fn test_3() {
-        let input = crate::util::read_file("inputs/2020_24_test.in");
+        let input = crate::util::read_file("inputs/2020_24_test.in").unwrap();
         let v = parse(&input);
         let mut tiles = Tiles::new(v);
         tiles.flip_by_day(2);
@@ -157,7 +158,7 @@ mod tests {
 
     #[test]
     fn test_4() {
-        let input = crate::util::read_file("inputs/2020_24_test.in");
+        let input = crate::util::read_file("inputs/2020_24_test.in").unwrap();
         let v = parse(&input);
         let mut tiles = Tiles::new(v);
         tiles.flip_by_day(3);
@@ -165,7 +166,7 @@ mod tests {
     }
     #[test]
     fn test_5() {
-        let input = crate::util::read_file("inputs/2020_24_test.in");
+        let input = crate::util::read_file("inputs/2020_24_test.in").unwrap();
         let v = parse(&input);
         let mut tiles = Tiles::new(v);
         tiles.flip_by_day(90);
@@ -173,7 +174,7 @@ mod tests {
     }
     #[test]
     fn test_6() {
-        let input = crate::util::read_file("inputs/2020_24_test.in");
+        let input = crate::util::read_file("inputs/2020_24_test.in").unwrap();
         let p2 = part2(&input);
         assert_eq!(p2, 2208);
     }
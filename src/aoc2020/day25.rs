@@ -1,10 +1,13 @@
+use crate::util::Error;
+use year_lib::{Answer, Solution, Timer};
+
 static DIVIDER: usize = 20201227;
 static SUBJECT: usize = 7;
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2020, 25);
-    let p1 = part1(&input);
-    println!("p1 {}", p1);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2020, 25))?;
+    let p1 = timer.part1(|| part1(&input));
+    Ok(Solution::new(p1, Answer::Unsolved))
 }
 
 fn parse(input: &str) -> (usize, usize) {
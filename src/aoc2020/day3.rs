@@ -1,9 +1,11 @@
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = get_puzzle_input(2020, 3);
-    println!("p1 {}", part1(&input));
-    println!("p2 {}", part2(&input));
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 3))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 fn tree_count(forest: &str, r_step: usize, d_step: usize) -> usize {
@@ -15,7 +17,6 @@ fn tree_count(forest: &str, r_step: usize, d_step: usize) -> usize {
         .count()
 }
 
-
 fn part1(forest: &str) -> usize {
     tree_count(forest, 3, 1)
 }
@@ -24,4 +25,4 @@ fn part2(forest: &str) -> usize {
     [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
         .iter()
         .fold(1, |acc, (r, d)| acc * tree_count(forest, *r, *d))
-}
\ No newline at end of file
+}
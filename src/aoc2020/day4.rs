@@ -1,22 +1,20 @@
 use std::ops::RangeInclusive;
 
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = get_puzzle_input(2020, 4);
-    let p1_count = part_1(&input);
-    let p2_count = part_2(&input);
-    println!("p1 {}", p1_count);
-    println!("p2 {}", p2_count);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 4))?;
+    let p1_count = timer.part1(|| part_1(&input));
+    let p2_count = timer.part2(|| part_2(&input));
+    Ok(Solution::new(p1_count, p2_count))
 }
 
 fn part_1(input: &str) -> usize {
     let fields = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
     input
         .split("\n\n")
-        .filter_map(|entry| {
-            Some(true).filter(|_| fields.iter().all(|x| entry.contains(x)))
-        })
+        .filter_map(|entry| Some(true).filter(|_| fields.iter().all(|x| entry.contains(x))))
         .count()
 }
 fn part_2(input: &str) -> usize {
@@ -1,18 +1,29 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
 
 use lazy_static::lazy_static;
 
 use regex::Regex;
+use year_lib::{Solution, Timer};
 
 lazy_static! {
     static ref BIG_BAG_RE: Regex = Regex::new(r"^(\w+ \w+) bag").unwrap();
     static ref SMALL_BAG_RE: Regex = Regex::new(r"(\d+) (\w+ \w+) bag").unwrap();
 }
 
-pub fn run() {
-    let input = get_puzzle_input(2020, 7);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 7))?;
+    let (small_to_big, big_to_small) = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&small_to_big));
+    let p2 = timer.part2(|| part2(&big_to_small));
+    Ok(Solution::new(p1, p2))
+}
+
+type BagGraph<'a> = HashMap<&'a str, Vec<(&'a str, usize)>>;
+
+/// Returns the bag graph in both directions: small to big and big to small
+fn parse(input: &str) -> (BagGraph<'_>, BagGraph<'_>) {
     let mut big_to_small = HashMap::new();
     let mut small_to_big = HashMap::new();
     input.lines().for_each(|line| {
@@ -32,10 +43,7 @@ pub fn run() {
                 .push((small_bag, quantity));
         });
     });
-    let p1 = part1(&small_to_big);
-    let p2 = part2(&big_to_small);
-    println!("p1 {}", p1);
-    println!("p2 {}", p2);
+    (small_to_big, big_to_small)
 }
 
 fn part1<'a>(small_to_big: &HashMap<&'a str, Vec<(&'a str, usize)>>) -> usize {
@@ -1,17 +1,28 @@
 use std::collections::HashSet;
 
-use crate::util::get_puzzle_input;
+use crate::{
+    parse::{ParseError, Span},
+    util::{get_puzzle_input, Error},
+};
 
-use lazy_static::lazy_static;
+use year_lib::{Solution, Timer};
 
-use regex::Regex;
-
-lazy_static! {
-    static ref REG: Regex = Regex::new(r"^(\w+) ([-+]\d+)").unwrap();
-}
 #[derive(Debug, Clone)]
 struct Instruction<'a>(&'a str, isize);
 
+fn parse(input: &str) -> Result<Vec<Instruction<'_>>, ParseError> {
+    Span::new(input)
+        .lines()
+        .map(|line| {
+            let [instruction, value] = line.scan("{} {}")?;
+            match instruction.as_str() {
+                "acc" | "jmp" | "nop" => Ok(Instruction(instruction.as_str(), value.parse()?)),
+                other => Err(instruction.error(format!("unknown instruction `{}`", other))),
+            }
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 struct State<'a> {
     current_line: isize,
@@ -21,16 +32,7 @@ struct State<'a> {
 }
 
 impl<'a> State<'a> {
-    pub fn new(input: &'a str) -> Self {
-        let instructions = input
-            .lines()
-            .map(|x| {
-                let matches = REG.captures(x).unwrap();
-                let instruction = matches.get(1).unwrap().as_str();
-                let value = matches.get(2).unwrap().as_str().parse::<isize>().unwrap();
-                Instruction(instruction, value)
-            })
-            .collect::<Vec<Instruction>>();
+    pub fn new(instructions: Vec<Instruction<'a>>) -> Self {
         Self {
             current_line: 0,
             acc: 0,
@@ -62,7 +64,6 @@ impl<'a> State<'a> {
     fn is_naturally_terminated(&self) -> bool {
         self.current_line as usize == self.instructions.len()
     }
-    #[allow(dead_code)]
     fn reset(&mut self) {
         self.acc = 0;
         self.current_line = 0;
@@ -70,22 +71,31 @@ impl<'a> State<'a> {
     }
 }
 
-pub fn run() {
-    let input = get_puzzle_input(2020, 8);
-    let p1 = part1(&input);
-    println!("p1 {}", p1);
-    let p2 = part2(&input);
-    println!("p2 {}", p2);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 8))?;
+    let instructions = timer.parse(|| parse(&input))?;
+    let p1 = timer.part1(|| part1(&instructions));
+    let p2 = timer.part2(|| part2(&instructions));
+    Ok(Solution::new(p1, p2))
 }
 
-fn part1(input: &str) -> isize {
-    let mut state = State::new(input);
+/// Repairs the program in place instead of cloning it for every attempt
+pub fn run_no_clone(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 8))?;
+    let instructions = timer.parse(|| parse(&input))?;
+    let p1 = timer.part1(|| part1(&instructions));
+    let p2 = timer.part2(|| part2_no_clone(&instructions));
+    Ok(Solution::new(p1, p2))
+}
+
+fn part1(instructions: &[Instruction]) -> isize {
+    let mut state = State::new(instructions.to_vec());
     state.execute_all();
     state.acc
 }
 
-fn part2(input: &str) -> isize {
-    let init_state = State::new(input);
+fn part2(instructions: &[Instruction]) -> isize {
+    let init_state = State::new(instructions.to_vec());
     let mut result_acc = 0;
     init_state
         .instructions
@@ -107,9 +117,8 @@ fn part2(input: &str) -> isize {
     result_acc
 }
 
-#[allow(dead_code)]
-fn part2_no_clone(input: &str) -> isize {
-    let mut state = State::new(input);
+fn part2_no_clone(instructions: &[Instruction]) -> isize {
+    let mut state = State::new(instructions.to_vec());
     let mut result_acc = 0;
     for i in 0..state.instructions.len() {
         if state.instructions[i].0 == "acc" {
@@ -149,7 +158,7 @@ mod tests {
                         acc +1\n\
                         jmp -4\n\
                         acc +6";
-        let acc = part1(input);
+        let acc = part1(&parse(input).unwrap());
         assert_eq!(5, acc);
     }
 
@@ -164,7 +173,7 @@ mod tests {
                         acc +1\n\
                         jmp -4\n\
                         acc +6";
-        let acc = part2(input);
+        let acc = part2(&parse(input).unwrap());
         assert_eq!(8, acc);
     }
 
@@ -179,7 +188,18 @@ mod tests {
                         acc +1\n\
                         jmp -4\n\
                         acc +6";
-        let acc = part2_no_clone(input);
+        let acc = part2_no_clone(&parse(input).unwrap());
         assert_eq!(8, acc);
     }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse("nop +0\nacc 4\njmp x").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 5));
+        let error = parse("nop +0\nadd +1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unknown instruction `add`"
+        );
+    }
 }
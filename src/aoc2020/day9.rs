@@ -1,15 +1,17 @@
-use crate::util::get_puzzle_input;
+use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = get_puzzle_input(2020, 9);
-    let numbers = input
-        .lines()
-        .map(|l| l.parse().unwrap())
-        .collect::<Vec<isize>>();
-    let p1 = part1(&numbers);
-    println!("{}", p1);
-    let p2 = part2(&numbers, p1);
-    println!("{}", p2);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input(2020, 9))?;
+    let numbers = timer.parse(|| {
+        input
+            .lines()
+            .map(|l| l.parse().unwrap())
+            .collect::<Vec<isize>>()
+    });
+    let p1 = timer.part1(|| part1(&numbers));
+    let p2 = timer.part2(|| part2(&numbers, p1));
+    Ok(Solution::new(p1, p2))
 }
 fn part1(numbers: &[isize]) -> isize {
     let mut sum = numbers.iter().take(25).sum::<isize>();
@@ -1,10 +1,12 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 1);
-    let v = parse(&input);
-    let p1 = part1(&v);
-    println!("Part 1: {} ", p1);
-    let p2 = part2(&v);
-    println!("Part 2: {} ", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 1))?;
+    let v = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&v));
+    let p2 = timer.part2(|| part2(&v));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> Vec<u32> {
@@ -32,7 +34,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_1_test.in");
+        let input = crate::util::read_file("inputs/2021_1_test.in").unwrap();
         let v = parse(&input);
         let p1 = part1(&v);
         assert_eq!(p1, 7);
@@ -40,7 +42,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_1_test.in");
+        let input = crate::util::read_file("inputs/2021_1_test.in").unwrap();
         let v = parse(&input);
         let p2 = part2(&v);
         assert_eq!(p2, 5);
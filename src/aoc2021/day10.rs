@@ -1,9 +1,11 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 10);
-    let p1 = part1(&input);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&input);
-    println!("Part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 10))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 fn part1(input: &str) -> usize {
@@ -66,14 +68,12 @@ fn get_line_score(s: &str) -> LineScore {
     if stack.is_empty() {
         LineScore::Corrupted(0)
     } else {
-        let score = stack.iter().rev().fold(0, |acc, i| {
-            match i {
-                ')' => acc * 5 + 1,
-                ']' => acc * 5 + 2,
-                '}' => acc * 5 + 3,
-                '>' => acc * 5 + 4,
-                _ => unreachable!(),
-            }
+        let score = stack.iter().rev().fold(0, |acc, i| match i {
+            ')' => acc * 5 + 1,
+            ']' => acc * 5 + 2,
+            '}' => acc * 5 + 3,
+            '>' => acc * 5 + 4,
+            _ => unreachable!(),
         });
         LineScore::Incomplete(score)
     }
@@ -85,14 +85,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_10_test.in");
+        let input = crate::util::read_file("inputs/2021_10_test.in").unwrap();
         let p1 = part1(&input);
         assert_eq!(p1, 26397);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_10_test.in");
+        let input = crate::util::read_file("inputs/2021_10_test.in").unwrap();
         let p2 = part2(&input);
         assert_eq!(p2, 288957);
     }
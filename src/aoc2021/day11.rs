@@ -1,42 +1,26 @@
-static NEIGHBOUR_INDEX: [(i8, i8); 8] = [
-    (1, 0),
-    (-1, 0),
-    (0, 1),
-    (0, -1),
-    (1, 1),
-    (1, -1),
-    (-1, 1),
-    (-1, -1),
-];
+use crate::util::Error;
+use year_lib::{Solution, Timer};
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 11);
-    let mut cavern = parse(&input);
-    let mut cloned_cavern = cavern.clone();
-    let p1 = part1(&mut cavern);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&mut cloned_cavern);
-    println!("Part 2: {}", p2);
+use crate::grid::Grid;
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 11))?;
+    let mut cavern = timer.parse(|| parse(&input));
+    let mut cloned_cavern = timer.parse(|| cavern.clone());
+    let p1 = timer.part1(|| part1(&mut cavern));
+    let p2 = timer.part2(|| part2(&mut cloned_cavern));
+    Ok(Solution::new(p1, p2))
 }
 #[derive(Debug, Clone)]
 struct Cavern {
-    map: Vec<Vec<u8>>,
+    map: Grid<u8>,
 }
 
 impl Cavern {
-    fn get_neighbour_indexes(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
-        let len = self.map.len() as i8;
-        NEIGHBOUR_INDEX
-            .iter()
-            .map(move |(dx, dy)| (x as i8 + dx, y as i8 + dy))
-            .filter(move |(x, y)| *x >= 0 && *y >= 0 && *x < len && *y < len)
-            .map(|(x, y)| (x as usize, y as usize))
-    }
-
     fn apply_steps_until_all_flash(&mut self) -> usize {
-        let len = self.map.len();
+        let len = self.map.cells().len();
         for i in 1.. {
-            if self.apply_step() == len * len {
+            if self.apply_step() == len {
                 return i;
             }
         }
@@ -49,34 +33,31 @@ impl Cavern {
 
     fn apply_step(&mut self) -> usize {
         // increase everyone by one
-        self.map.iter_mut().flatten().for_each(|cell| {
+        self.map.cells_mut().iter_mut().for_each(|cell| {
             *cell += 1;
         });
         // use a stack for flash
-        let len = self.map.len();
-        let mut stack = vec![];
-        for y in 0..len {
-            for x in 0..len {
-                if self.map[y][x] == 10 {
-                    stack.push((x, y));
-                }
-            }
-        }
+        let mut stack = self
+            .map
+            .iter()
+            .filter(|(_, cell)| **cell == 10)
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
         // propagate flash
         let mut flash_count = 0;
         while let Some(current) = stack.pop() {
-            for (x, y) in self.get_neighbour_indexes(current.0, current.1) {
-                self.map[y][x] += 1;
-                if self.map[y][x] == 10 {
-                    stack.push((x, y));
+            for pos in self.map.neighbours8(current).collect::<Vec<_>>() {
+                self.map[pos] += 1;
+                if self.map[pos] == 10 {
+                    stack.push(pos);
                 }
             }
             flash_count += 1;
         }
         // reset flashed ones to 0
         self.map
+            .cells_mut()
             .iter_mut()
-            .flatten()
             .filter(|c| **c > 9)
             .for_each(|c| *c = 0);
         flash_count
@@ -84,16 +65,9 @@ impl Cavern {
 }
 
 fn parse(input: &str) -> Cavern {
-    let map = input
-        .trim()
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as u8)
-                .collect()
-        })
-        .collect();
-    Cavern { map }
+    Cavern {
+        map: Grid::parse(input.trim(), |c| c.to_digit(10).unwrap() as u8),
+    }
 }
 
 fn part1(cavern: &mut Cavern) -> usize {
@@ -115,14 +89,14 @@ mod tests {
                         19191\n\
                         19991\n\
                         11111";
-        let mut cavern = parse(&input);
+        let mut cavern = parse(input);
         assert_eq!(cavern.apply_step(), 9);
         assert_eq!(cavern.apply_step(), 0);
     }
 
     #[test]
     fn test_1_2() {
-        let input = crate::util::read_file("inputs/2021_11_test.in");
+        let input = crate::util::read_file("inputs/2021_11_test.in").unwrap();
         let mut cavern = parse(&input);
         assert_eq!(cavern.apply_steps(10), 204);
         assert_eq!(cavern.apply_steps(90), 1656 - 204);
@@ -130,7 +104,7 @@ mod tests {
 
     #[test]
     fn test_2_1() {
-        let input = crate::util::read_file("inputs/2021_11_test.in");
+        let input = crate::util::read_file("inputs/2021_11_test.in").unwrap();
         let mut cavern = parse(&input);
         assert_eq!(cavern.apply_steps_until_all_flash(), 195);
     }
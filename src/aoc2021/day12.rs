@@ -1,13 +1,14 @@
+use crate::util::Error;
 use fxhash::{FxHashMap, FxHasher};
 use std::hash::{Hash, Hasher};
+use year_lib::{Solution, Timer};
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 12);
-    let graph = parse(&input);
-    let p1 = graph.create_paths(true);
-    println!("Part 1: {}", p1);
-    let p2 = graph.create_paths(false);
-    println!("Part 2: {}", p2);
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 12))?;
+    let graph = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| graph.create_paths(true));
+    let p2 = timer.part2(|| graph.create_paths(false));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> Graph {
@@ -107,7 +108,7 @@ mod tests {
                         b-d\n\
                         A-end\n\
                         b-end";
-        let graph = parse(&input);
+        let graph = parse(input);
         assert_eq!(graph.create_paths(true), 10);
     }
 
@@ -123,13 +124,13 @@ mod tests {
                         kj-sa\n\
                         kj-HN\n\
                         kj-dc";
-        let graph = parse(&input);
+        let graph = parse(input);
         assert_eq!(graph.create_paths(true), 19);
     }
 
     #[test]
     fn test_1_3() {
-        let input = crate::util::read_file("inputs/2021_12_test.in");
+        let input = crate::util::read_file("inputs/2021_12_test.in").unwrap();
         let graph = parse(&input);
         assert_eq!(graph.create_paths(true), 226);
     }
@@ -143,7 +144,7 @@ mod tests {
                         b-d\n\
                         A-end\n\
                         b-end";
-        let graph = parse(&input);
+        let graph = parse(input);
         assert_eq!(graph.create_paths(false), 36);
     }
 
@@ -159,13 +160,13 @@ mod tests {
                         kj-sa\n\
                         kj-HN\n\
                         kj-dc";
-        let graph = parse(&input);
+        let graph = parse(input);
         assert_eq!(graph.create_paths(false), 103);
     }
 
     #[test]
     fn test_2_3() {
-        let input = crate::util::read_file("inputs/2021_12_test.in");
+        let input = crate::util::read_file("inputs/2021_12_test.in").unwrap();
         let graph = parse(&input);
         assert_eq!(graph.create_paths(false), 3509);
     }
@@ -1,10 +1,14 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 13);
-    let mut ins = parse(&input);
-    let p1 = part1(&mut ins);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&mut ins);
-    println!("Part 2:\n{}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+use crate::{grid::Grid, ocr, point::P2};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 13))?;
+    let mut ins = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&mut ins));
+    let p2 = timer.part2(|| part2(&mut ins));
+    Ok(Solution::new(p1, p2))
 }
 
 fn part1(ins: &mut Instruction) -> usize {
@@ -14,7 +18,7 @@ fn part1(ins: &mut Instruction) -> usize {
 
 fn part2(ins: &mut Instruction) -> String {
     ins.apply_all_folds();
-    format!("{}", ins)
+    ocr::read(&ins.image())
 }
 
 fn parse(input: &str) -> Instruction {
@@ -24,15 +28,15 @@ fn parse(input: &str) -> Instruction {
         .unwrap()
         .lines()
         .map(|line| {
-            let mut line_it = line.split(',').map(|s| s.trim().parse::<usize>().unwrap());
+            let mut line_it = line.split(',').map(|s| s.trim().parse::<i64>().unwrap());
             let x = line_it.next().unwrap();
             let y = line_it.next().unwrap();
-            Point::new(x, y)
+            P2::new(x, y)
         })
         .collect::<Vec<_>>();
-    let max_x = points.iter().map(|p| p.x).max().unwrap();
-    let max_y = points.iter().map(|p| p.y).max().unwrap();
-    let max_point = Point::new(max_x, max_y);
+    let max_x = points.iter().map(|p| p.x()).max().unwrap();
+    let max_y = points.iter().map(|p| p.y()).max().unwrap();
+    let max_point = P2::new(max_x, max_y);
     let fold_stack = it
         .next()
         .unwrap()
@@ -46,28 +50,16 @@ fn parse(input: &str) -> Instruction {
         fold_stack,
     }
 }
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl Point {
-    fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
-}
-
 enum Fold {
-    OnY(usize), // horizontal line
-    OnX(usize), // vertical line
+    OnY(i64), // horizontal line
+    OnX(i64), // vertical line
 }
 
 impl From<&str> for Fold {
     fn from(s: &str) -> Self {
         let mut it = s.strip_prefix("fold along ").unwrap().split('=');
         let axis = it.next().unwrap();
-        let value = it.next().unwrap().parse::<usize>().unwrap();
+        let value = it.next().unwrap().parse::<i64>().unwrap();
         match axis {
             "x" => Fold::OnX(value),
             "y" => Fold::OnY(value),
@@ -77,31 +69,23 @@ impl From<&str> for Fold {
 }
 
 struct Instruction {
-    points: Vec<Point>,
-    max_point: Point,
+    points: Vec<P2>,
+    max_point: P2,
     fold_stack: Vec<Fold>,
 }
 
 impl Instruction {
     fn apply_fold(&mut self, fold: Fold) {
-        let mut max_point = self.max_point;
-        match fold {
-            Fold::OnY(y) => {
-                self.points
-                    .iter_mut()
-                    .filter(|p| p.y > y)
-                    .for_each(|p| p.y = max_point.y - p.y);
-                max_point.y = y - 1;
-            }
-            Fold::OnX(x) => {
-                self.points
-                    .iter_mut()
-                    .filter(|p| p.x > x)
-                    .for_each(|p| p.x = max_point.x - p.x);
-                max_point.x = x - 1;
-            }
-        }
-        self.max_point = max_point;
+        let (axis, line) = match fold {
+            Fold::OnX(x) => (0, x),
+            Fold::OnY(y) => (1, y),
+        };
+        let max = self.max_point[axis];
+        self.points
+            .iter_mut()
+            .filter(|p| p[axis] > line)
+            .for_each(|p| p[axis] = max - p[axis]);
+        self.max_point[axis] = line - 1;
         self.points.sort_unstable();
         self.points.dedup();
     }
@@ -116,22 +100,14 @@ impl Instruction {
         let fold = self.fold_stack.pop().unwrap();
         self.apply_fold(fold);
     }
-}
-
-impl std::fmt::Display for Instruction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut s = String::new();
-        for y in 0..=self.max_point.y {
-            for x in 0..=self.max_point.x {
-                if self.points.contains(&Point::new(x, y)) {
-                    s.push('█');
-                } else {
-                    s.push('.');
-                }
-            }
-            s.push('\n');
+    /// The dots on the folded paper
+    fn image(&self) -> Grid<bool> {
+        let (width, height) = (self.max_point.x() + 1, self.max_point.y() + 1);
+        let mut image = Grid::filled(width as usize, height as usize, false);
+        for p in &self.points {
+            image[(p.x() as usize, p.y() as usize)] = true;
         }
-        write!(f, "{}", s)
+        image
     }
 }
 
@@ -141,7 +117,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_13_test.in");
+        let input = crate::util::read_file("inputs/2021_13_test.in").unwrap();
         let mut ins = parse(&input);
         let p1 = part1(&mut ins);
         assert_eq!(p1, 17);
@@ -149,16 +125,17 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_13_test.in");
+        let input = crate::util::read_file("inputs/2021_13_test.in").unwrap();
         let mut ins = parse(&input);
         let p2 = part2(&mut ins);
-        let res = "█████\n\
-                        █...█\n\
-                        █...█\n\
-                        █...█\n\
-                        █████\n\
-                        .....\n\
-                        .....\n";
+        // a square is not a letter, the answer is the drawing
+        let res = "#####\n\
+                   #...#\n\
+                   #...#\n\
+                   #...#\n\
+                   #####\n\
+                   .....\n\
+                   .....";
         assert_eq!(p2, res);
     }
 }
@@ -1,12 +1,13 @@
+use crate::util::Error;
 use ahash::AHashMap;
+use year_lib::{Solution, Timer};
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 14);
-    let mut polymer = parse(&input);
-    let p1 = part_core(&mut polymer, 10);
-    println!("Part 1: {}", p1);
-    let p2 = part_core(&mut polymer, 40 - 10);
-    println!("Part 2: {}", p2);
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 14))?;
+    let mut polymer = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part_core(&mut polymer, 10));
+    let p2 = timer.part2(|| part_core(&mut polymer, 40 - 10));
+    Ok(Solution::new(p1, p2))
 }
 
 fn part_core(polymer: &mut Polymer, steps: usize) -> usize {
@@ -22,11 +23,9 @@ fn parse(input: &str) -> Polymer {
     let unit_array = input_parts.next().unwrap().as_bytes();
     let first = unit_array[0];
     let last = unit_array[unit_array.len() - 1];
-    unit_array
-        .windows(2)
-        .for_each(|window| {
-            *units.entry((window[0], window[1])).or_insert(0) += 1;
-        });
+    unit_array.windows(2).for_each(|window| {
+        *units.entry((window[0], window[1])).or_insert(0) += 1;
+    });
     let rules = input_parts
         .next()
         .unwrap()
@@ -91,7 +90,7 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_14_test.in");
+        let input = crate::util::read_file("inputs/2021_14_test.in").unwrap();
         let mut polymer = parse(&input);
         let p1 = part_core(&mut polymer, 10);
         assert_eq!(p1, 1588);
@@ -99,7 +98,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_14_test.in");
+        let input = crate::util::read_file("inputs/2021_14_test.in").unwrap();
         let mut polymer = parse(&input);
         let p1 = part_core(&mut polymer, 40);
         assert_eq!(p1, 2188189693529);
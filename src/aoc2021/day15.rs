@@ -1,29 +1,37 @@
-use std::collections::BinaryHeap;
+use crate::util::Error;
+use year_lib::{Solution, Timer};
 
-use fxhash::FxHashMap;
-use fxhash::FxHashSet;
+use crate::{grid::NEIGHBOURS_4, search};
 
-static NEIGHBOUR_INDEX: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 15))?;
+    let mat = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&mat, manhattan_distance));
+    let p2 = timer.part2(|| part2(&mat, manhattan_distance));
+    Ok(Solution::new(p1, p2))
+}
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 15);
-    let mat = parse(&input);
-    let p1 = part1(&mat);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&mat);
-    println!("Part 2: {}", p2);
+/// Guides the search with the euclidean distance instead of the manhattan one
+pub(crate) fn run_euclidean(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 15))?;
+    let mat = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&mat, euclidean_distance));
+    let p2 = timer.part2(|| part2(&mat, euclidean_distance));
+    Ok(Solution::new(p1, p2))
 }
 
-fn part1(mat: &[Vec<usize>]) -> usize {
+type Heuristic = fn((usize, usize), (usize, usize)) -> usize;
+
+fn part1(mat: &[Vec<usize>], heuristic: Heuristic) -> usize {
     let start = (0, 0);
     let finish = (mat.len() - 1, mat.len() - 1);
-    a_star(mat, start, finish, false)
+    a_star(mat, start, finish, false, heuristic)
 }
 
-fn part2(mat: &[Vec<usize>]) -> usize {
+fn part2(mat: &[Vec<usize>], heuristic: Heuristic) -> usize {
     let start = (0, 0);
     let finish = (mat.len() * 5 - 1, mat.len() * 5 - 1);
-    a_star(mat, start, finish, true)
+    a_star(mat, start, finish, true, heuristic)
 }
 
 fn parse(input: &str) -> Vec<Vec<usize>> {
@@ -41,7 +49,6 @@ fn manhattan_distance(p_1: (usize, usize), p_2: (usize, usize)) -> usize {
     ((p_2.0 as isize - p_1.0 as isize).abs() + (p_2.1 as isize - p_1.1 as isize).abs()) as usize
 }
 
-#[allow(dead_code)]
 fn euclidean_distance(p_1: (usize, usize), p_2: (usize, usize)) -> usize {
     (((p_2.0 as isize - p_1.0 as isize).abs().pow(2)
         + (p_2.1 as isize - p_1.1 as isize).abs().pow(2)) as f64)
@@ -49,75 +56,23 @@ fn euclidean_distance(p_1: (usize, usize), p_2: (usize, usize)) -> usize {
         .round() as usize
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-struct Point {
-    x: usize,
-    y: usize,
-    cost: usize,
-    heuristic: usize,
-    total: usize,
-}
-
-impl Point {
-    fn new(x: usize, y: usize, cost: usize, heuristic: usize) -> Point {
-        Point {
-            x,
-            y,
-            cost,
-            heuristic,
-            total: cost + heuristic,
-        }
-    }
-}
-
-impl Ord for Point {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        // Reverse order
-        other.total.cmp(&self.total)
-    }
-}
-
-impl PartialOrd for Point {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 fn a_star(
     mat: &[Vec<usize>],
     start: (usize, usize),
     finish: (usize, usize),
     expand_mat: bool,
+    heuristic: Heuristic,
 ) -> usize {
-    let mut opened = BinaryHeap::new();
-    let mut closed = FxHashSet::default();
-    let mut cheapest_cost_map = FxHashMap::default();
-    cheapest_cost_map.insert(start, 0);
-    let starting_point = Point::new(start.0, start.1, 0, manhattan_distance(start, finish));
-    opened.push(starting_point);
-    while let Some(current) = opened.pop() {
-        if current.x == finish.0 && current.y == finish.1 {
-            return current.cost;
-        }
-        closed.insert((current.x, current.y));
-        for n in get_neighbour_indexes(mat, current.x, current.y, expand_mat)
-            .filter(|n| !closed.contains(&(n.0, n.1)))
-        {
-            let n_cost = current.cost + get_point_cost(mat, n.0, n.1);
-            // check it is worse than cheapest path
-            if cheapest_cost_map
-                .get(&n)
-                .filter(|&c| *c <= n_cost)
-                .is_some()
-            {
-                continue;
-            }
-            cheapest_cost_map.insert(n, n_cost);
-            let new_p = Point::new(n.0, n.1, n_cost, manhattan_distance(n, finish));
-            opened.push(new_p);
-        }
-    }
-    unreachable!()
+    search::astar(
+        start,
+        |&(x, y)| {
+            get_neighbour_indexes(mat, x, y, expand_mat).map(|n| (n, get_point_cost(mat, n.0, n.1)))
+        },
+        |&p| heuristic(p, finish),
+        |&p| p == finish,
+    )
+    .unwrap()
+    .1
 }
 
 fn get_point_cost(mat: &[Vec<usize>], x: usize, y: usize) -> usize {
@@ -142,7 +97,7 @@ fn get_neighbour_indexes(
     if expand_mat {
         len *= 5;
     }
-    NEIGHBOUR_INDEX
+    NEIGHBOURS_4
         .iter()
         .map(move |(dx, dy)| (x as isize + dx, y as isize + dy))
         .filter(move |(x, y)| *x >= 0 && *y >= 0 && *x < len && *y < len)
@@ -156,17 +111,19 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_15_test.in");
+        let input = crate::util::read_file("inputs/2021_15_test.in").unwrap();
         let v = parse(&input);
-        let p1 = part1(&v);
+        let p1 = part1(&v, manhattan_distance);
         assert_eq!(p1, 40);
+        assert_eq!(part1(&v, euclidean_distance), 40);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_15_test.in");
+        let input = crate::util::read_file("inputs/2021_15_test.in").unwrap();
         let v = parse(&input);
-        let p1 = part2(&v);
+        let p1 = part2(&v, manhattan_distance);
         assert_eq!(p1, 315);
+        assert_eq!(part2(&v, euclidean_distance), 315);
     }
 }
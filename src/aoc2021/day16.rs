@@ -1,12 +1,13 @@
+use crate::util::Error;
 use bitvec::vec::BitVec;
+use year_lib::{Solution, Timer};
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 16);
-    let packet = parse(&input);
-    let p1 = sum_versions(&packet);
-    println!("Part 1: {}", p1);
-    let p2 = evaluate(&packet);
-    println!("Part 2: {}", p2);
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 16))?;
+    let packet = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| sum_versions(&packet));
+    let p2 = timer.part2(|| evaluate(&packet));
+    Ok(Solution::new(p1, p2))
 }
 
 fn sum_versions(packet: &Packet) -> usize {
@@ -214,9 +215,9 @@ mod tests {
     #[test]
     fn test_1_1() {
         let input = "D2FE28";
-        let v = parse(&input);
+        let v = parse(input);
         assert_eq!(v.version, 6);
-        assert_eq!(v.inner_packet.is_inner_packet(), true);
+        assert!(v.inner_packet.is_inner_packet());
         assert_eq!(
             v.inner_packet,
             InnerPacket::Literal(LiteralPacket { value: 2021 })
@@ -225,18 +226,18 @@ mod tests {
     #[test]
     fn test_1_2() {
         let input = "38006F45291200";
-        let v = parse(&input);
-        assert_eq!(v.inner_packet.is_operator_packet(), true);
+        let v = parse(input);
+        assert!(v.inner_packet.is_operator_packet());
         match &v.inner_packet {
             InnerPacket::Literal(_) => panic!("should be operator"),
             InnerPacket::Operator(op) => {
                 assert!(op.sub_packets.len() == 2);
-                assert_eq!(op.sub_packets[0].inner_packet.is_inner_packet(), true);
+                assert!(op.sub_packets[0].inner_packet.is_inner_packet());
                 assert_eq!(
                     op.sub_packets[0].inner_packet,
                     InnerPacket::Literal(LiteralPacket { value: 10 })
                 );
-                assert_eq!(op.sub_packets[1].inner_packet.is_inner_packet(), true);
+                assert!(op.sub_packets[1].inner_packet.is_inner_packet());
                 assert_eq!(
                     op.sub_packets[1].inner_packet,
                     InnerPacket::Literal(LiteralPacket { value: 20 })
@@ -248,23 +249,23 @@ mod tests {
     #[test]
     fn test_1_3() {
         let input = "EE00D40C823060";
-        let v = parse(&input);
-        assert_eq!(v.inner_packet.is_operator_packet(), true);
+        let v = parse(input);
+        assert!(v.inner_packet.is_operator_packet());
         match &v.inner_packet {
             InnerPacket::Literal(_) => panic!("should be operator"),
             InnerPacket::Operator(op) => {
                 assert!(op.sub_packets.len() == 3);
-                assert_eq!(op.sub_packets[0].inner_packet.is_inner_packet(), true);
+                assert!(op.sub_packets[0].inner_packet.is_inner_packet());
                 assert_eq!(
                     op.sub_packets[0].inner_packet,
                     InnerPacket::Literal(LiteralPacket { value: 1 })
                 );
-                assert_eq!(op.sub_packets[1].inner_packet.is_inner_packet(), true);
+                assert!(op.sub_packets[1].inner_packet.is_inner_packet());
                 assert_eq!(
                     op.sub_packets[1].inner_packet,
                     InnerPacket::Literal(LiteralPacket { value: 2 })
                 );
-                assert_eq!(op.sub_packets[2].inner_packet.is_inner_packet(), true);
+                assert!(op.sub_packets[2].inner_packet.is_inner_packet());
                 assert_eq!(
                     op.sub_packets[2].inner_packet,
                     InnerPacket::Literal(LiteralPacket { value: 3 })
@@ -276,56 +277,56 @@ mod tests {
     #[test]
     fn test_2_1() {
         let input = "C200B40A82";
-        let p = parse(&input);
+        let p = parse(input);
         assert_eq!(evaluate(&p), 3);
     }
 
     #[test]
     fn test_2_2() {
         let input = "04005AC33890";
-        let p = parse(&input);
+        let p = parse(input);
         assert_eq!(evaluate(&p), 54);
     }
 
     #[test]
     fn test_2_3() {
         let input = "880086C3E88112";
-        let p = parse(&input);
+        let p = parse(input);
         assert_eq!(evaluate(&p), 7);
     }
 
     #[test]
     fn test_2_4() {
         let input = "CE00C43D881120";
-        let p = parse(&input);
+        let p = parse(input);
         assert_eq!(evaluate(&p), 9);
     }
 
     #[test]
     fn test_2_5() {
         let input = "D8005AC2A8F0";
-        let p = parse(&input);
+        let p = parse(input);
         assert_eq!(evaluate(&p), 1);
     }
 
     #[test]
     fn test_2_6() {
         let input = "F600BC2D8F";
-        let p = parse(&input);
+        let p = parse(input);
         assert_eq!(evaluate(&p), 0);
     }
 
     #[test]
     fn test_2_7() {
         let input = "9C005AC2F8F0";
-        let p = parse(&input);
+        let p = parse(input);
         assert_eq!(evaluate(&p), 0);
     }
 
     #[test]
     fn test_2_8() {
         let input = "9C0141080250320F1802104A08";
-        let p = parse(&input);
+        let p = parse(input);
         assert_eq!(evaluate(&p), 1);
     }
 }
@@ -1,10 +1,12 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 17);
-    let hb = parse(&input);
-    let p1 = part1(&hb);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&hb);
-    println!("Part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 17))?;
+    let hb = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&hb));
+    let p2 = timer.part2(|| part2(&hb));
+    Ok(Solution::new(p1, p2))
 }
 
 struct HitBox {
@@ -52,7 +54,7 @@ fn part1(hb: &HitBox) -> usize {
     // any more than that and we will miss the target.
     // v_x can get 0 eventually so before any step before v_y_init+1 steps we can set x to correct zone with 0 velocity and it will stay in it.
     // so, y_min = -vy_init - 1
-    let v_y_init_max = (-hb.y_min - 1).abs() as usize;
+    let v_y_init_max = (-hb.y_min - 1).unsigned_abs() as usize;
     // gauss sum for height
     v_y_init_max * (v_y_init_max + 1) / 2
 }
@@ -95,7 +97,7 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_17_test.in");
+        let input = crate::util::read_file("inputs/2021_17_test.in").unwrap();
         let v = parse(&input);
         let p1 = part1(&v);
         assert_eq!(p1, 45);
@@ -103,7 +105,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_17_test.in");
+        let input = crate::util::read_file("inputs/2021_17_test.in").unwrap();
         let v = parse(&input);
         let p2 = part2(&v);
         assert_eq!(p2, 112);
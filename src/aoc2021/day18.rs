@@ -1,14 +1,15 @@
+use crate::util::Error;
 use itertools::Itertools;
 use std::{iter::Peekable, str::Chars};
-
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 18);
-    let v = parse(&input);
-    let cloned_v = v.clone();
-    let p1 = part1(v);
-    println!("Part 1: {}", p1);
-    let p2 = part2(cloned_v);
-    println!("Part 2: {}", p2);
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 18))?;
+    let v = timer.parse(|| parse(&input));
+    let cloned_v = timer.parse(|| v.clone());
+    let p1 = timer.part1(|| part1(v));
+    let p2 = timer.part2(|| part2(cloned_v));
+    Ok(Solution::new(p1, p2))
 }
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Element {
@@ -188,7 +189,7 @@ mod tests {
     #[test]
     fn test_0_1() {
         let input = "[[1,2],3]";
-        let v = parse(&input);
+        let v = parse(input);
         assert_eq!(v.len(), 1);
         assert_eq!(
             v[0],
@@ -204,7 +205,7 @@ mod tests {
 
     #[test]
     fn test_0_2() {
-        let input = crate::util::read_file("inputs/2021_18_test.in");
+        let input = crate::util::read_file("inputs/2021_18_test.in").unwrap();
         let v = parse(&input);
         assert_eq!(v.len(), 10);
         assert_eq!(
@@ -233,7 +234,7 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_18_test.in");
+        let input = crate::util::read_file("inputs/2021_18_test.in").unwrap();
         let v = parse(&input);
         let p1 = part1(v);
         assert_eq!(p1, 4140);
@@ -241,7 +242,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_18_test.in");
+        let input = crate::util::read_file("inputs/2021_18_test.in").unwrap();
         let v = parse(&input);
         let p1 = part2(v);
         assert_eq!(p1, 3993);
@@ -1,14 +1,17 @@
+use crate::util::Error;
 use fxhash::FxHashSet;
 use itertools::Itertools;
+use year_lib::{Solution, Timer};
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 19);
-    let mut plane = parse(&input);
-    plane.merge_all_readings();
-    let p1 = part1(&plane);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&plane);
-    println!("Part 2: {}", p2);
+use crate::point::P3;
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 19))?;
+    let mut plane = timer.parse(|| parse(&input));
+    timer.parse(|| plane.merge_all_readings());
+    let p1 = timer.part1(|| part1(&plane));
+    let p2 = timer.part2(|| part2(&plane));
+    Ok(Solution::new(p1, p2))
 }
 
 fn part1(plane: &Plane3D) -> usize {
@@ -20,41 +23,11 @@ fn part2(plane: &Plane3D) -> usize {
         .total_distances
         .iter()
         .tuple_combinations()
-        .map(|([x1, y1, z1], [x2, y2, z2])| (x1 - x2).abs() + (y1 - y2).abs() + (z1 - z2).abs())
+        .map(|(&p1, &p2)| p1.manhattan(p2))
         .max()
         .unwrap() as usize
 }
 
-fn rotate([x, y, z]: [i32; 3], rot: u8) -> [i32; 3] {
-    match rot {
-        0 => [x, y, z],
-        1 => [x, z, -y],
-        2 => [x, -y, -z],
-        3 => [x, -z, y],
-        4 => [y, x, -z],
-        5 => [y, z, x],
-        6 => [y, -x, z],
-        7 => [y, -z, -x],
-        8 => [z, x, y],
-        9 => [z, y, -x],
-        10 => [z, -x, -y],
-        11 => [z, -y, x],
-        12 => [-x, y, -z],
-        13 => [-x, z, y],
-        14 => [-x, -y, z],
-        15 => [-x, -z, -y],
-        16 => [-y, x, z],
-        17 => [-y, z, -x],
-        18 => [-y, -x, -z],
-        19 => [-y, -z, x],
-        20 => [-z, x, -y],
-        21 => [-z, y, x],
-        22 => [-z, -x, y],
-        23 => [-z, -y, -x],
-        _ => unreachable!(),
-    }
-}
-
 fn parse(input: &str) -> Plane3D {
     let mut readings = input
         .split("\n\n")
@@ -64,18 +37,16 @@ fn parse(input: &str) -> Plane3D {
                 .skip(1)
                 .map(|l| {
                     let mut parts = l.split(',');
-                    let x = parts.next().unwrap().parse::<i32>().unwrap();
-                    let y = parts.next().unwrap().parse::<i32>().unwrap();
-                    let z = parts.next().unwrap().parse::<i32>().unwrap();
-                    [x, y, z]
+                    let x = parts.next().unwrap().parse().unwrap();
+                    let y = parts.next().unwrap().parse().unwrap();
+                    let z = parts.next().unwrap().parse().unwrap();
+                    P3::new(x, y, z)
                 })
                 .collect::<Vec<_>>();
             let beacon_distance_set = readings
                 .iter()
                 .tuple_combinations()
-                .map(|([x1, y1, z1], [x2, y2, z2])| {
-                    ((x1 - x2).abs() + (y1 - y2).abs() + (z1 - z2).abs()) as usize
-                })
+                .map(|(&p1, &p2)| p1.manhattan(p2) as usize)
                 .collect::<FxHashSet<_>>();
             ScannerReading {
                 beacons: readings,
@@ -98,15 +69,15 @@ fn parse(input: &str) -> Plane3D {
 
 struct Plane3D {
     readings: Vec<ScannerReading>,
-    beacon_locations: FxHashSet<[i32; 3]>,
+    beacon_locations: FxHashSet<P3>,
     beacon_distance_sets: Vec<FxHashSet<usize>>,
-    total_distances: Vec<[i32; 3]>,
+    total_distances: Vec<P3>,
 }
 
 impl Plane3D {
     fn merge_all_readings(&mut self) {
         let mut total_distances = Vec::with_capacity(self.readings.len() + 1);
-        total_distances.push([0, 0, 0]);
+        total_distances.push(P3::ORIGIN);
         while !self.readings.is_empty() {
             for i in (0..self.readings.len()).rev() {
                 if let Some(d) = Plane3D::merge_reading(
@@ -123,10 +94,10 @@ impl Plane3D {
     }
 
     fn merge_reading(
-        beacon_locations: &mut FxHashSet<[i32; 3]>,
+        beacon_locations: &mut FxHashSet<P3>,
         beacon_distance_sets: &mut Vec<FxHashSet<usize>>,
         reading: &ScannerReading,
-    ) -> Option<[i32; 3]> {
+    ) -> Option<P3> {
         // For at least 12 intersections with rotation, we need 12 choose 2 = 66 intersections of beacon distances.
         // We can skip impossible reading matches by checking this condition first.
         let scanner_intersections = beacon_distance_sets
@@ -140,14 +111,14 @@ impl Plane3D {
             let rotated = reading
                 .beacons
                 .iter()
-                .map(|&v| rotate(v, r))
+                .map(|&v| v.rotate(r))
                 .collect::<Vec<_>>();
             let distances = beacon_locations
                 .iter()
                 .cartesian_product(&rotated)
-                .map(|([x1, y1, z1], [x2, y2, z2])| [x1 - x2, y1 - y2, z1 - z2]);
-            for [dx, dy, dz] in distances {
-                let altered_rotated = rotated.iter().map(|[x, y, z]| [x + dx, y + dy, z + dz]);
+                .map(|(&p1, &p2)| p1 - p2);
+            for d in distances {
+                let altered_rotated = rotated.iter().map(|&p| p + d);
                 if altered_rotated
                     .clone()
                     .filter(|v| beacon_locations.contains(v))
@@ -156,7 +127,7 @@ impl Plane3D {
                 {
                     beacon_locations.extend(altered_rotated);
                     beacon_distance_sets.push(reading.beacon_distance_set.clone());
-                    return Some([dx, dy, dz]);
+                    return Some(d);
                 }
             }
         }
@@ -165,7 +136,7 @@ impl Plane3D {
 }
 
 struct ScannerReading {
-    beacons: Vec<[i32; 3]>,
+    beacons: Vec<P3>,
     beacon_distance_set: FxHashSet<usize>,
 }
 
@@ -176,7 +147,7 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_19_test.in");
+        let input = crate::util::read_file("inputs/2021_19_test.in").unwrap();
         let mut plane = parse(&input);
         plane.merge_all_readings();
         let p1 = part1(&plane);
@@ -185,7 +156,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_19_test.in");
+        let input = crate::util::read_file("inputs/2021_19_test.in").unwrap();
         let mut plane = parse(&input);
         plane.merge_all_readings();
         let p2 = part2(&plane);
@@ -1,15 +1,16 @@
+use crate::util::Error;
 use once_cell::sync::OnceCell;
 use regex::Regex;
+use year_lib::{Solution, Timer};
 
 static REG: OnceCell<Regex> = OnceCell::new();
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 2);
-    let v = parse(&input);
-    let p1 = part1(&v);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&v);
-    println!("Part 2: {}", p2);
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 2))?;
+    let v = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&v));
+    let p2 = timer.part2(|| part2(&v));
+    Ok(Solution::new(p1, p2))
 }
 
 enum Op {
@@ -66,7 +67,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_2_test.in");
+        let input = crate::util::read_file("inputs/2021_2_test.in").unwrap();
         let v = parse(&input);
         let p1 = part1(&v);
         assert_eq!(p1, 150);
@@ -74,7 +75,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_2_test.in");
+        let input = crate::util::read_file("inputs/2021_2_test.in").unwrap();
         let v = parse(&input);
         let p2 = part2(&v);
         assert_eq!(p2, 900);
@@ -1,3 +1,6 @@
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
 const ZONE: [(isize, isize); 9] = [
     (-1, -1),
     (0, -1),
@@ -10,13 +13,12 @@ const ZONE: [(isize, isize); 9] = [
     (1, 1),
 ];
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 20);
-    let mut im = parse(&input);
-    let p1 = part_core(&mut im, 2);
-    println!("Part 1: {}", p1);
-    let p2 = part_core(&mut im, 50 - 2);
-    println!("Part 2: {}", p2);
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 20))?;
+    let mut im = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part_core(&mut im, 2));
+    let p2 = timer.part2(|| part_core(&mut im, 50 - 2));
+    Ok(Solution::new(p1, p2))
 }
 
 fn part_core(im: &mut Image, n: usize) -> usize {
@@ -55,7 +57,7 @@ impl Image {
         let y_len = self.image.len();
         let x_len = self.image[0].len();
         let mut new_image = vec![vec![false; self.image[0].len() + 2]; self.image.len() + 2];
-        for (y,row) in new_image.iter_mut().enumerate() {
+        for (y, row) in new_image.iter_mut().enumerate() {
             for (x, value) in row.iter_mut().enumerate() {
                 let index = ZONE
                     .iter()
@@ -100,7 +102,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_20_test.in");
+        let input = crate::util::read_file("inputs/2021_20_test.in").unwrap();
         let mut im = parse(&input);
         let p1 = part_core(&mut im, 2);
         assert_eq!(p1, 35);
@@ -108,7 +110,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_20_test.in");
+        let input = crate::util::read_file("inputs/2021_20_test.in").unwrap();
         let mut im = parse(&input);
         let p1 = part_core(&mut im, 50);
         assert_eq!(p1, 3351);
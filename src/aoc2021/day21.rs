@@ -1,16 +1,17 @@
 const PRACTICE_WIN_SCORE: u16 = 1000;
 const QUANTUM_WIN_SCORE: u8 = 21;
 
+use crate::util::Error;
 use fxhash::FxHashMap;
 use itertools::Itertools;
+use year_lib::{Solution, Timer};
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 21);
-    let game = parse(&input);
-    let p1 = game.practice_play();
-    println!("Part 1: {}", p1);
-    let p2 = game.quantum_play();
-    println!("Part 2: {}", p2);
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 21))?;
+    let game = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| game.practice_play());
+    let p2 = timer.part2(|| game.quantum_play());
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> DiracDieGame {
@@ -150,14 +151,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_21_test.in");
+        let input = crate::util::read_file("inputs/2021_21_test.in").unwrap();
         let game = parse(&input);
         let p1 = game.practice_play();
         assert_eq!(p1, 739785);
     }
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_21_test.in");
+        let input = crate::util::read_file("inputs/2021_21_test.in").unwrap();
         let game = parse(&input);
         let p1 = game.quantum_play();
         assert_eq!(p1, 444356092776315);
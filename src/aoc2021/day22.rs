@@ -1,10 +1,12 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 22);
-    let v = parse(&input);
-    let p1 = part1(&v);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&v);
-    println!("Part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 22))?;
+    let v = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&v));
+    let p2 = timer.part2(|| part2(&v));
+    Ok(Solution::new(p1, p2))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -179,14 +181,14 @@ mod tests {
                             on x=11..13,y=11..13,z=11..13\n\
                             off x=9..11,y=9..11,z=9..11\n\
                             on x=10..10,y=10..10,z=10..10";
-        let v = parse(&input);
+        let v = parse(input);
         let p1 = part1(&v);
         assert_eq!(p1, 39);
     }
 
     #[test]
     fn test_1_2() {
-        let input = crate::util::read_file("inputs/2021_22_test_1.in");
+        let input = crate::util::read_file("inputs/2021_22_test_1.in").unwrap();
         let v = parse(&input);
         let p1 = part1(&v);
         assert_eq!(p1, 590784);
@@ -194,7 +196,7 @@ mod tests {
 
     #[test]
     fn test_1_3() {
-        let input = crate::util::read_file("inputs/2021_22_test_2.in");
+        let input = crate::util::read_file("inputs/2021_22_test_2.in").unwrap();
         let v = parse(&input);
         let p1 = part1(&v);
         assert_eq!(p1, 474140);
@@ -202,7 +204,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_22_test_2.in");
+        let input = crate::util::read_file("inputs/2021_22_test_2.in").unwrap();
         let v = parse(&input);
         let p2 = part2(&v);
         assert_eq!(p2, 2758514936282235);
@@ -1,7 +1,7 @@
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use crate::util::Error;
+use year_lib::{Answer, Solution, Timer};
 
-use fxhash::FxHashSet;
+use crate::search;
 
 static VALID_COORDS: &[(i32, i32)] = &[
     (0, 0),
@@ -32,9 +32,9 @@ static VALID_COORDS: &[(i32, i32)] = &[
 fn dist(from: u8, to: u8) -> u32 {
     let f = &VALID_COORDS[from as usize];
     let t = &VALID_COORDS[to as usize];
-    (f.0 - t.0).abs() as u32
+    (f.0 - t.0).unsigned_abs()
         + if f.0 == t.0 {
-            (f.1 - t.1).abs() as u32
+            (f.1 - t.1).unsigned_abs()
         } else {
             (f.1 + t.1) as u32
         }
@@ -105,7 +105,7 @@ fn possible_moves(p: &[u8; 23]) -> Vec<(u8, u8)> {
                         }
                     }
                 }
-                results.push((up as u8, down))
+                results.push((up, down))
             }
         }
     }
@@ -144,48 +144,17 @@ fn do_move(p: &[u8; 23], m: (u8, u8)) -> ([u8; 23], u32) {
     (new_p, d * step)
 }
 
-#[derive(Eq, PartialEq)]
-struct PosWithCost {
-    pos: [u8; 23],
-    cost: u32,
-}
-
-impl Ord for PosWithCost {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
-    }
-}
-
-impl PartialOrd for PosWithCost {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 fn shortest_path_cost(p0: &[u8; 23], p1: &[u8; 23]) -> Option<u32> {
-    let mut queue = BinaryHeap::new();
-    let mut seen = FxHashSet::<[u8; 23]>::default();
-    queue.push(PosWithCost { pos: *p0, cost: 0 });
-    while let Some(PosWithCost { pos, cost }) = queue.pop() {
-        if pos == *p1 {
-            // found
-            return Some(cost);
-        }
-        // if we've already seen this position skip
-        if seen.get(&pos).is_some() {
-            continue;
-        }
-        seen.insert(pos);
-        let moves = possible_moves(&pos);
-        for m in moves {
-            let (next, step_cost) = do_move(&pos, m);
-            queue.push(PosWithCost {
-                pos: next,
-                cost: cost + step_cost,
-            })
-        }
-    }
-    None
+    search::dijkstra(
+        *p0,
+        |&pos| {
+            possible_moves(&pos)
+                .into_iter()
+                .map(move |m| do_move(&pos, m))
+        },
+        |pos| pos == p1,
+    )
+    .map(|(_, cost)| cost)
 }
 
 fn parse(input: &str) -> [u8; 23] {
@@ -211,14 +180,14 @@ fn parse(input: &str) -> [u8; 23] {
     start_pos
 }
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2021, 23);
-    let start_pos = parse(&input);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 23))?;
+    let start_pos = timer.parse(|| parse(&input));
     let final_pos = [
         0u8, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4,
     ];
-    let p2 = shortest_path_cost(&start_pos, &final_pos).unwrap();
-    println!("Part 2: {}", p2);
+    let p2 = timer.part2(|| shortest_path_cost(&start_pos, &final_pos).unwrap());
+    Ok(Solution::new(Answer::Unsolved, p2))
 }
 
 #[cfg(test)]
@@ -227,7 +196,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_23_test.in");
+        let input = crate::util::read_file("inputs/2021_23_test.in").unwrap();
         let start_pos = parse(&input);
         let final_pos = [
             0u8, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4,
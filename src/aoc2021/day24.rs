@@ -1,13 +1,15 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 24);
-    let (p1, p2) = core(&input);
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 24))?;
+    let (p1, p2) = timer.parse(|| core(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 fn core(input: &str) -> (usize, usize) {
-    let mut v_max = vec![0; 14];
-    let mut v_min = vec![0; 14];
+    let mut v_max = [0; 14];
+    let mut v_min = [0; 14];
     let mut stack = vec![];
     let it_ins = input
         .split("inp w\n")
@@ -1,28 +1,24 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 25);
-    let mut s = parse(&input);
-    let p1 = s.apply_steps();
-    println!("Part 1: {}", p1);
+use crate::util::Error;
+use year_lib::{Answer, Solution, Timer};
+
+use crate::grid::Grid;
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 25))?;
+    let mut s = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| s.apply_steps());
+    Ok(Solution::new(p1, Answer::Unsolved))
 }
 
 fn parse(input: &str) -> SeaBed {
-    SeaBed(
-        input
-            .lines()
-            .map(|l| {
-                l.bytes()
-                    .map(|b| match b {
-                        b'>' => SeaBedSpace::EastFaced,
-                        b'v' => SeaBedSpace::SouthFaced,
-                        _ => SeaBedSpace::Empty,
-                    })
-                    .collect()
-            })
-            .collect(),
-    )
+    SeaBed(Grid::parse(input, |c| match c {
+        '>' => SeaBedSpace::EastFaced,
+        'v' => SeaBedSpace::SouthFaced,
+        _ => SeaBedSpace::Empty,
+    }))
 }
 
-struct SeaBed(Vec<Vec<SeaBedSpace>>);
+struct SeaBed(Grid<SeaBedSpace>);
 
 #[derive(Clone, PartialEq)]
 enum SeaBedSpace {
@@ -35,8 +31,8 @@ impl SeaBed {
     fn apply_steps(&mut self) -> usize {
         let mut count = 0;
         loop {
-            let move_x = self.step_east();
-            let move_y = self.step_south();
+            let move_x = self.step(SeaBedSpace::EastFaced, (1, 0));
+            let move_y = self.step(SeaBedSpace::SouthFaced, (0, 1));
             count += 1;
             if !(move_x || move_y) {
                 break;
@@ -45,38 +41,18 @@ impl SeaBed {
         count
     }
 
-    fn step_east(&mut self) -> bool {
-        let x_max = self.0[0].len();
+    /// Moves the herd facing the direction, all at once
+    fn step(&mut self, herd: SeaBedSpace, direction: (isize, isize)) -> bool {
         let mut moved = false;
         let mut new_bed = self.0.clone();
-        for (y, row) in self.0.iter().enumerate() {
-            for (x, val) in row.iter().enumerate() {
-                if SeaBedSpace::EastFaced == *val
-                    && SeaBedSpace::Empty == self.0[y][(x + 1) % x_max]
-                {
-                    new_bed[y][(x + 1) % x_max] = SeaBedSpace::EastFaced;
-                    new_bed[y][x] = SeaBedSpace::Empty;
-                    moved = true;
-                }
-            }
-        }
-        self.0 = new_bed;
-        moved
-    }
-
-    fn step_south(&mut self) -> bool {
-        let y_max = self.0.len();
-        let mut new_bed = self.0.clone();
-        let mut moved = false;
-        for (y, row) in self.0.iter().enumerate() {
-            for (x, val) in row.iter().enumerate() {
-                if SeaBedSpace::SouthFaced == *val
-                    && SeaBedSpace::Empty == self.0[(y + 1) % y_max][x]
-                {
-                    new_bed[(y + 1) % y_max][x] = SeaBedSpace::SouthFaced;
-                    new_bed[y][x] = SeaBedSpace::Empty;
-                    moved = true;
-                }
+        for (pos, val) in self.0.iter() {
+            let next = self.0.step_wrapping(pos, direction);
+            if let Some(next) =
+                next.filter(|&next| herd == *val && SeaBedSpace::Empty == self.0[next])
+            {
+                new_bed[next] = herd.clone();
+                new_bed[pos] = SeaBedSpace::Empty;
+                moved = true;
             }
         }
         self.0 = new_bed;
@@ -84,19 +60,13 @@ impl SeaBed {
     }
 }
 
-impl core::fmt::Display for SeaBed {
+impl core::fmt::Display for SeaBedSpace {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        for row in &self.0 {
-            for s in row {
-                match s {
-                    SeaBedSpace::EastFaced => write!(f, ">")?,
-                    SeaBedSpace::SouthFaced => write!(f, "v")?,
-                    _ => write!(f, ".")?,
-                }
-            }
-            writeln!(f)?;
+        match self {
+            SeaBedSpace::EastFaced => write!(f, ">"),
+            SeaBedSpace::SouthFaced => write!(f, "v"),
+            _ => write!(f, "."),
         }
-        Ok(())
     }
 }
 
@@ -106,7 +76,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_25_test.in");
+        let input = crate::util::read_file("inputs/2021_25_test.in").unwrap();
         let mut s = parse(&input);
         let p1 = s.apply_steps();
         assert_eq!(p1, 58);
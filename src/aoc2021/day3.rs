@@ -1,10 +1,12 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 3);
-    let (v, bit_len) = parse(&input);
-    let p1 = part1(&v, bit_len);
-    println!("Part 1: {}", p1);
-    let p2 = part2(v, bit_len);
-    println!("Part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 3))?;
+    let (v, bit_len) = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&v, bit_len));
+    let p2 = timer.part2(|| part2(v, bit_len));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> (Vec<usize>, usize) {
@@ -37,7 +39,7 @@ fn part2(v: Vec<usize>, bit_len: usize) -> usize {
     let oxy = (0..bit_len)
         .rev()
         .scan(v.clone(), |oxy, i| {
-            let flag = oxy.iter().filter(|&n| n & 1 << i > 0).count() >= (oxy.len() + 1) / 2;
+            let flag = oxy.iter().filter(|&n| n & 1 << i > 0).count() >= oxy.len().div_ceil(2);
             remove_unworthy(oxy, i, !flag);
             oxy.first().copied()
         })
@@ -47,7 +49,7 @@ fn part2(v: Vec<usize>, bit_len: usize) -> usize {
     let co2 = (0..bit_len)
         .rev()
         .scan(v, |co2, i| {
-            let flag = co2.iter().filter(|&n| n & 1 << i > 0).count() >= (co2.len() + 1) / 2;
+            let flag = co2.iter().filter(|&n| n & 1 << i > 0).count() >= co2.len().div_ceil(2);
             remove_unworthy(co2, i, flag);
             co2.first().copied()
         })
@@ -74,14 +76,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_3_test.in");
+        let input = crate::util::read_file("inputs/2021_3_test.in").unwrap();
         let (v, bit_len) = parse(&input);
         let p1 = part1(&v, bit_len);
         assert_eq!(p1, 198);
     }
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_3_test.in");
+        let input = crate::util::read_file("inputs/2021_3_test.in").unwrap();
         let (v, bit_len) = parse(&input);
         let p2 = part2(v, bit_len);
         assert_eq!(p2, 230);
@@ -1,13 +1,14 @@
+use crate::util::Error;
 use core::fmt;
-
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 4);
-    let (drawn, mut boards) = parse(&input);
-    let mut cloned_boards = boards.clone();
-    let p1 = part1(&drawn, &mut boards);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&drawn, &mut cloned_boards);
-    println!("Part 2: {}", p2);
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 4))?;
+    let (drawn, mut boards) = timer.parse(|| parse(&input));
+    let mut cloned_boards = timer.parse(|| boards.clone());
+    let p1 = timer.part1(|| part1(&drawn, &mut boards));
+    let p2 = timer.part2(|| part2(&drawn, &mut cloned_boards));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> (Vec<usize>, Vec<Board>) {
@@ -161,14 +162,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_4_test.in");
+        let input = crate::util::read_file("inputs/2021_4_test.in").unwrap();
         let (d, mut boards) = parse(&input);
         let p1 = part1(&d, &mut boards);
         assert_eq!(p1, 4512);
     }
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_4_test.in");
+        let input = crate::util::read_file("inputs/2021_4_test.in").unwrap();
         let (d, mut boards) = parse(&input);
         let p2 = part2(&d, &mut boards);
         assert_eq!(p2, 1924);
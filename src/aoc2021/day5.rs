@@ -1,20 +1,23 @@
 use core::fmt;
 
+use crate::util::Error;
 use once_cell::sync::OnceCell;
 use regex::Regex;
+use year_lib::{Solution, Timer};
+
+use crate::point::P2;
 
 static REG: OnceCell<Regex> = OnceCell::new();
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 5);
-    let v = parse(&input);
-    let p1 = part1(&v);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&v);
-    println!("Part 2: {}", p2);
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 5))?;
+    let v = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&v));
+    let p2 = timer.part2(|| part2(&v));
+    Ok(Solution::new(p1, p2))
 }
 
-fn parse(input: &str) -> Vec<(Point, Point)> {
+fn parse(input: &str) -> Vec<(P2, P2)> {
     REG.get_or_init(|| regex::Regex::new(r"^(\d+),(\d+)\s+->\s+(\d+),(\d+)").unwrap());
     input
         .lines()
@@ -24,33 +27,14 @@ fn parse(input: &str) -> Vec<(Point, Point)> {
             let y_1 = matches.get(2).unwrap().as_str().parse().unwrap();
             let x_2 = matches.get(3).unwrap().as_str().parse().unwrap();
             let y_2 = matches.get(4).unwrap().as_str().parse().unwrap();
-            (Point::new(x_1, y_1), Point::new(x_2, y_2))
+            (P2::new(x_1, y_1), P2::new(x_2, y_2))
         })
         .collect()
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl Point {
-    fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-    // get the points in the middle
-    fn get_middle_points(&self, other: &Self) -> Vec<Point> {
-        let dx = (other.x - self.x).signum();
-        let dy = (other.y - self.y).signum();
-        let (mut x, mut y) = (self.x, self.y);
-        let mut v = vec![];
-        while (x, y) != (other.x + dx, other.y + dy) {
-            v.push(Point::new(x, y));
-            x += dx;
-            y += dy;
-        }
-        v
-    }
+/// The points of the line from one end to the other, both included
+fn line(from: P2, to: P2) -> impl Iterator<Item = P2> {
+    let step = (to - from).signum();
+    (0..=from.chebyshev(to)).map(move |i| from + step * i)
 }
 
 struct Graph {
@@ -58,21 +42,14 @@ struct Graph {
 }
 
 impl Graph {
-    fn new(points: &[(Point, Point)]) -> Self {
-        let max_point = points
-            .iter()
-            .scan(Point::new(0, 0), |max_point, (p1, p2)| {
-                max_point.x = max_point.x.max(p1.x.max(p2.x));
-                max_point.y = max_point.y.max(p1.y.max(p2.y));
-                Some(*max_point)
-            })
-            .last()
-            .unwrap();
-        let mut grid = vec![vec![0; max_point.x as usize + 1]; max_point.y as usize + 1];
-        points.iter().for_each(|(p1, p2)| {
-            let mid = p1.get_middle_points(p2);
-            mid.iter().for_each(|p| {
-                grid[p.y as usize][p.x as usize] += 1;
+    fn new(points: &[(P2, P2)]) -> Self {
+        let ends = points.iter().flat_map(|&(p1, p2)| [p1, p2]);
+        let max_x = ends.clone().map(P2::x).max().unwrap();
+        let max_y = ends.map(P2::y).max().unwrap();
+        let mut grid = vec![vec![0; max_x as usize + 1]; max_y as usize + 1];
+        points.iter().for_each(|&(p1, p2)| {
+            line(p1, p2).for_each(|p| {
+                grid[p.y() as usize][p.x() as usize] += 1;
             });
         });
         Graph { grid }
@@ -99,17 +76,17 @@ impl fmt::Display for Graph {
     }
 }
 
-fn part1(v: &[(Point, Point)]) -> usize {
+fn part1(v: &[(P2, P2)]) -> usize {
     let filtered_v = v
         .iter()
-        .filter(|(p1, p2)| p1.x == p2.x || p1.y == p2.y)
+        .filter(|(p1, p2)| p1.x() == p2.x() || p1.y() == p2.y())
         .copied()
         .collect::<Vec<_>>();
     let g = Graph::new(&filtered_v);
     g.how_many_dangerous()
 }
 
-fn part2(v: &[(Point, Point)]) -> usize {
+fn part2(v: &[(P2, P2)]) -> usize {
     let g = Graph::new(v);
     g.how_many_dangerous()
 }
@@ -120,14 +97,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_5_test.in");
+        let input = crate::util::read_file("inputs/2021_5_test.in").unwrap();
         let v = parse(&input);
         let p1 = part1(&v);
         assert_eq!(p1, 5);
     }
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_5_test.in");
+        let input = crate::util::read_file("inputs/2021_5_test.in").unwrap();
         let v = parse(&input);
         let p2 = part2(&v);
         assert_eq!(p2, 12);
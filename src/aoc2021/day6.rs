@@ -1,10 +1,12 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 6);
-    let mut l = parse(&input);
-    let p1 = solve(&mut l, 80);
-    println!("Part 1: {}", p1);
-    let p2 = solve(&mut l, 256 - 80);
-    println!("Part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 6))?;
+    let mut l = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| solve(&mut l, 80));
+    let p2 = timer.part2(|| solve(&mut l, 256 - 80));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> LanternFishes {
@@ -42,21 +44,21 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_6_test.in");
+        let input = crate::util::read_file("inputs/2021_6_test.in").unwrap();
         let mut l = parse(&input);
         let p1 = solve(&mut l, 18);
         assert_eq!(p1, 26);
     }
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_6_test.in");
+        let input = crate::util::read_file("inputs/2021_6_test.in").unwrap();
         let mut l = parse(&input);
         let p1 = solve(&mut l, 80);
         assert_eq!(p1, 5934);
     }
     #[test]
     fn test_3() {
-        let input = crate::util::read_file("inputs/2021_6_test.in");
+        let input = crate::util::read_file("inputs/2021_6_test.in").unwrap();
         let mut l = parse(&input);
         let p1 = solve(&mut l, 256);
         assert_eq!(p1, 26984457539);
@@ -1,11 +1,13 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 7);
-    let mut array = parse(&input);
-    array.sort_unstable();
-    let p1 = part1(&array);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&array);
-    println!("Part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 7))?;
+    let mut array = timer.parse(|| parse(&input));
+    timer.parse(|| array.sort_unstable());
+    let p1 = timer.part1(|| part1(&array));
+    let p2 = timer.part2(|| part2(&array));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> Vec<isize> {
@@ -13,10 +15,10 @@ fn parse(input: &str) -> Vec<isize> {
 }
 
 fn median(sorted_array: &[isize]) -> isize {
-    if (sorted_array.len() % 2) == 0 {
+    if sorted_array.len().is_multiple_of(2) {
         let ind_left = sorted_array.len() / 2 - 1;
         let ind_right = sorted_array.len() / 2;
-        ((sorted_array[ind_left] + sorted_array[ind_right]) / 2) as isize
+        (sorted_array[ind_left] + sorted_array[ind_right]) / 2
     } else {
         sorted_array[sorted_array.len() / 2]
     }
@@ -54,7 +56,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_7_test.in");
+        let input = crate::util::read_file("inputs/2021_7_test.in").unwrap();
         let mut array = parse(&input);
         array.sort();
         let p1 = part1(&array);
@@ -63,7 +65,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_7_test.in");
+        let input = crate::util::read_file("inputs/2021_7_test.in").unwrap();
         let array = parse(&input);
         let p1 = part2(&array);
         assert_eq!(p1, 168);
@@ -1,74 +1,49 @@
-use std::collections::{HashMap, HashSet};
+use crate::util::Error;
+use std::collections::HashSet;
+use year_lib::{Solution, Timer};
+
+use crate::grid::Grid;
 
-static NEIGHBOUR_INDEX: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
 static MAX_HEIGHT_VALUE: u8 = 9;
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2021, 9);
-    let caves = parse(&input);
-    let p1 = part1(&caves);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&caves);
-    println!("Part 2: {}", p2);
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2021, 9))?;
+    let caves = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&caves));
+    let p2 = timer.part2(|| part2(&caves));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> Caves {
-    let area = input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, s)| {
-            s.chars().enumerate().map(move |(x, c)| {
-                (
-                    Point::new(x as isize, y as isize),
-                    c.to_digit(10).unwrap() as u8,
-                )
-            })
-        })
-        .collect();
-    Caves { area }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    x: isize,
-    y: isize,
-}
-
-impl Point {
-    fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-
-    fn neighbours(&self) -> impl Iterator<Item = Point> + '_ {
-        NEIGHBOUR_INDEX
-            .iter()
-            .map(move |(dx, dy)| Point::new(self.x + dx, self.y + dy))
+    Caves {
+        area: Grid::parse(input, |c| c.to_digit(10).unwrap() as u8),
     }
 }
 
 struct Caves {
-    area: HashMap<Point, u8>,
+    area: Grid<u8>,
 }
 
 impl Caves {
-    fn get_bottom_points(&self) -> impl Iterator<Item = (&Point, &u8)> + '_ {
-        self.area.iter().filter(move |(p, v)| {
-            p.neighbours()
-                .all(|p2| self.area.get(&p2).unwrap_or(&MAX_HEIGHT_VALUE) > v)
-        })
+    fn get_bottom_points(&self) -> impl Iterator<Item = ((usize, usize), &u8)> + '_ {
+        self.area
+            .iter()
+            .filter(move |(p, v)| self.area.neighbours4(*p).all(|p2| self.area[p2] > **v))
     }
 
     fn get_basin_sizes(&self) -> impl Iterator<Item = usize> + '_ {
         self.get_bottom_points().map(move |(p, _v)| {
-            let mut stack = vec![*p];
+            let mut stack = vec![p];
             let mut result_set = HashSet::new();
             while let Some(current) = stack.pop() {
                 if result_set.contains(&current) {
                     continue;
                 }
-                stack.extend(current.neighbours().filter(|p2| {
-                    self.area.get(p2).unwrap_or(&MAX_HEIGHT_VALUE) < &MAX_HEIGHT_VALUE
-                }));
+                stack.extend(
+                    self.area
+                        .neighbours4(current)
+                        .filter(|p2| self.area[*p2] < MAX_HEIGHT_VALUE),
+                );
                 result_set.insert(current);
             }
             result_set.len()
@@ -95,14 +70,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2021_9_test.in");
+        let input = crate::util::read_file("inputs/2021_9_test.in").unwrap();
         let caves = parse(&input);
         let p1 = part1(&caves);
         assert_eq!(p1, 15);
     }
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2021_9_test.in");
+        let input = crate::util::read_file("inputs/2021_9_test.in").unwrap();
         let caves = parse(&input);
         let p2 = part2(&caves);
         assert_eq!(p2, 1134);
@@ -1,9 +1,11 @@
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2022, 1);
-    let p1 = part1(&input);
-    println!("part 1: {}", p1);
-    let p2 = part2(&input);
-    println!("part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2022, 1))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 pub fn part1(input: &str) -> u64 {
@@ -28,14 +30,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2022_1_test.in");
+        let input = crate::util::read_file("inputs/2022_1_test.in").unwrap();
         let p1 = part1(&input);
         assert_eq!(p1, 24000);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2022_1_test.in");
+        let input = crate::util::read_file("inputs/2022_1_test.in").unwrap();
         let p2 = part2(&input);
         assert_eq!(p2, 45000);
     }
@@ -1,11 +1,13 @@
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2022, 10);
-    let p1 = part1(&input);
-    println!("p1: {}", p1);
-    let p2 = part2(&input);
-    for line in p2 {
-        println!("{}", line);
-    }
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+use crate::{grid::Grid, ocr};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2022, 10))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> Vec<Op> {
@@ -70,10 +72,15 @@ fn part1(input: &str) -> i32 {
     wanted_signals.iter().sum()
 }
 
-fn part2(input: &str) -> Vec<String> {
+fn part2(input: &str) -> String {
+    ocr::read(&draw(input))
+}
+
+/// Pixels lit by the CRT while the program runs
+fn draw(input: &str) -> Grid<bool> {
     let ops = parse(input);
     let v = Instructions::new(ops).0;
-    let mut display = vec![vec!['.'; 40]; 6];
+    let mut display = Grid::filled(40, 6, false);
     let mut register_prev = 1i32;
     let mut c_prev = 0;
     for (c, register) in v {
@@ -81,17 +88,13 @@ fn part2(input: &str) -> Vec<String> {
             let y = i / 40;
             let x = i % 40;
             if register_prev - 1 <= x && x <= register_prev + 1 {
-                display[y as usize][x as usize] = '#';
+                display[(x as usize, y as usize)] = true;
             }
         }
         c_prev = c;
         register_prev = register;
     }
-
     display
-        .iter()
-        .map(|row| row.iter().collect::<String>())
-        .collect::<Vec<_>>()
 }
 
 #[cfg(test)]
@@ -100,16 +103,16 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2022_10_test.in");
+        let input = crate::util::read_file("inputs/2022_10_test.in").unwrap();
         let p1 = part1(&input);
         assert_eq!(p1, 13140);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2022_10_test.in");
-        let p2 = part2(&input);
-        let expected = vec![
+        let input = crate::util::read_file("inputs/2022_10_test.in").unwrap();
+        let display = ocr::draw(&draw(&input));
+        let expected = [
             "##..##..##..##..##..##..##..##..##..##..",
             "###...###...###...###...###...###...###.",
             "####....####....####....####....####....",
@@ -117,6 +120,6 @@ mod tests {
             "######......######......######......####",
             "#######.......#######.......#######.....",
         ];
-        assert_eq!(p2, expected);
+        assert_eq!(display, expected.join("\n"));
     }
 }
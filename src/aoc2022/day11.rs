@@ -1,11 +1,13 @@
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2022, 11);
-    let mut v = parse(&input);
-    let mut v_clone = v.clone();
-    let p1 = play_monkey_business(&mut v, 20, true);
-    println!("p1: {}", p1);
-    let p2 = play_monkey_business(&mut v_clone, 10_000, false);
-    println!("p2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2022, 11))?;
+    let mut v = timer.parse(|| parse(&input));
+    let mut v_clone = timer.parse(|| v.clone());
+    let p1 = timer.part1(|| play_monkey_business(&mut v, 20, true));
+    let p2 = timer.part2(|| play_monkey_business(&mut v_clone, 10_000, false));
+    Ok(Solution::new(p1, p2))
 }
 
 fn play_monkey_business(v: &mut [Monkey], turn: usize, boring: bool) -> usize {
@@ -81,7 +83,7 @@ impl Eq for Monkey {}
 
 impl PartialOrd for Monkey {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.id.cmp(&other.id))
+        Some(self.cmp(other))
     }
 }
 
@@ -163,7 +165,7 @@ impl DivisibleTest {
     }
 
     fn test(&self, num: usize) -> usize {
-        if num % self.divisible_test == 0 {
+        if num.is_multiple_of(self.divisible_test) {
             self.success_id
         } else {
             self.failure_id
@@ -177,7 +179,7 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2022_11_test.in");
+        let input = crate::util::read_file("inputs/2022_11_test.in").unwrap();
         let mut v = parse(&input);
         let p1 = play_monkey_business(&mut v, 20, true);
         assert_eq!(p1, 10605);
@@ -185,7 +187,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2022_11_test.in");
+        let input = crate::util::read_file("inputs/2022_11_test.in").unwrap();
         let mut v = parse(&input);
         let p2 = play_monkey_business(&mut v, 10_000, false);
         assert_eq!(p2, 2713310158);
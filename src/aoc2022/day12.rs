@@ -1,3 +1,107 @@
-pub fn run(){
-    todo!()
-}
\ No newline at end of file
+use year_lib::{Solution, Timer};
+
+use crate::{grid::Grid, search, util::Error};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2022, 12))?;
+    let map = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&map));
+    let p2 = timer.part2(|| part2(&map));
+    Ok(Solution::new(p1, p2))
+}
+
+/// Searches the path up the hill from both ends
+pub(crate) fn run_bidirectional(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2022, 12))?;
+    let map = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1_bidirectional(&map));
+    let p2 = timer.part2(|| part2(&map));
+    Ok(Solution::new(p1, p2))
+}
+
+struct HeightMap {
+    heights: Grid<u8>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl HeightMap {
+    /// Squares reachable from the given one, at most one higher
+    fn climb(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let height = self.heights[pos];
+        self.heights
+            .neighbours4(pos)
+            .filter(move |&n| self.heights[n] <= height + 1)
+    }
+
+    /// Squares the given one is reachable from
+    fn descend(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let height = self.heights[pos];
+        self.heights
+            .neighbours4(pos)
+            .filter(move |&n| height <= self.heights[n] + 1)
+    }
+}
+
+fn parse(input: &str) -> HeightMap {
+    let map = Grid::parse(input, |c| c as u8);
+    let start = map.find(|&c| c == b'S').unwrap();
+    let end = map.find(|&c| c == b'E').unwrap();
+    let heights = map.map(|&c| match c {
+        b'S' => b'a',
+        b'E' => b'z',
+        c => c,
+    });
+    HeightMap {
+        heights,
+        start,
+        end,
+    }
+}
+
+fn part1(map: &HeightMap) -> usize {
+    let path = search::bfs(map.start, |&pos| map.climb(pos), |&pos| pos == map.end).unwrap();
+    path.len() - 1
+}
+
+fn part1_bidirectional(map: &HeightMap) -> usize {
+    let path = search::bidirectional_bfs(
+        map.start,
+        map.end,
+        |&pos| map.climb(pos),
+        |&pos| map.descend(pos),
+    )
+    .unwrap();
+    path.len() - 1
+}
+
+fn part2(map: &HeightMap) -> usize {
+    // from the top down to the closest lowest square
+    let path = search::bfs(
+        map.end,
+        |&pos| map.descend(pos),
+        |&pos| map.heights[pos] == b'a',
+    )
+    .unwrap();
+    path.len() - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let input = crate::util::read_file("inputs/2022_12_test.in").unwrap();
+        let map = parse(&input);
+        assert_eq!(part1(&map), 31);
+        assert_eq!(part1_bidirectional(&map), 31);
+    }
+
+    #[test]
+    fn test_2() {
+        let input = crate::util::read_file("inputs/2022_12_test.in").unwrap();
+        let map = parse(&input);
+        assert_eq!(part2(&map), 29);
+    }
+}
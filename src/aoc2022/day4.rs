@@ -1,17 +1,18 @@
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2022, 4);
-    let p1 = part1(&input);
-    println!("p1: {}", p1);
-    let p2 = part2(&input);
-    println!("p2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2022, 4))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 pub fn parse(input: &str) -> impl Iterator<Item = (u8, u8, u8, u8)> + '_ {
     input
         .lines()
         .map(|pair| {
-            pair
-                .split(',')
+            pair.split(',')
                 .flat_map(|a| a.split('-').map(|i| i.parse::<u8>().unwrap()))
                 .collect::<Vec<_>>()
         })
@@ -25,9 +26,7 @@ pub fn part1(input: &str) -> u64 {
 }
 
 pub fn part2(input: &str) -> u64 {
-    parse(input)
-        .filter(|(a, b, m, n)| m <= b && a <= n)
-        .count() as u64
+    parse(input).filter(|(a, b, m, n)| m <= b && a <= n).count() as u64
 }
 
 #[cfg(test)]
@@ -35,14 +34,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2022_4_test.in");
+        let input = crate::util::read_file("inputs/2022_4_test.in").unwrap();
         let p1 = part1(&input);
         assert_eq!(p1, 2);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2022_4_test.in");
+        let input = crate::util::read_file("inputs/2022_4_test.in").unwrap();
         let p1 = part2(&input);
         assert_eq!(p1, 4);
     }
@@ -1,11 +1,15 @@
-pub fn run() {
-    let (s, i) = parse(&crate::util::get_puzzle_input(2022, 5));
-    let p1 = part1(s.clone(), &i);
-    println!("p1: {}", p1);
-    let p2 = part2(s, &i);
-    println!("p2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2022, 5))?;
+    let (s, i) = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(s.clone(), &i));
+    let p2 = timer.part2(|| part2(s, &i));
+    Ok(Solution::new(p1, p2))
 }
 
+#[allow(clippy::type_complexity)]
 pub fn parse(input: &str) -> (Vec<Vec<char>>, Vec<(usize, usize, usize)>) {
     let mut iter = input.split("\n\n");
     let stack_input = iter.next().unwrap();
@@ -83,14 +87,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2022_5_test.in");
+        let input = crate::util::read_file("inputs/2022_5_test.in").unwrap();
         let (s, i) = parse(&input);
         let p1 = part1(s, &i);
         assert_eq!(p1, "CMZ");
     }
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2022_5_test.in");
+        let input = crate::util::read_file("inputs/2022_5_test.in").unwrap();
         let (s, i) = parse(&input);
         let p2 = part2(s, &i);
         assert_eq!(p2, "MCD");
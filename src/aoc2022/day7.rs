@@ -1,13 +1,14 @@
+use crate::util::Error;
 use std::collections::HashMap;
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2022, 7);
-    let lines = to_line_vec(&input);
-    let (root, _) = traverse(&lines, 0);
-    let p1 = part1(&root);
-    println!("Part 1: {}", p1);
-    let p2 = part2(&root);
-    println!("Part 2: {}", p2);
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2022, 7))?;
+    let lines = timer.parse(|| to_line_vec(&input));
+    let (root, _) = timer.parse(|| traverse(&lines, 0));
+    let p1 = timer.part1(|| part1(&root));
+    let p2 = timer.part2(|| part2(&root));
+    Ok(Solution::new(p1, p2))
 }
 
 pub fn part1(root: &Node) -> u64 {
@@ -106,7 +107,7 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2022_7_test.in");
+        let input = crate::util::read_file("inputs/2022_7_test.in").unwrap();
         let lines = to_line_vec(&input);
         let (root, _) = traverse(&lines, 0);
         let p1 = part1(&root);
@@ -115,7 +116,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2022_7_test.in");
+        let input = crate::util::read_file("inputs/2022_7_test.in").unwrap();
         let lines = to_line_vec(&input);
         let (root, _) = traverse(&lines, 0);
         let p1 = part2(&root);
@@ -1,11 +1,14 @@
+use crate::util::Error;
 use std::collections::HashSet;
+use year_lib::{Solution, Timer};
 
-pub fn run() {
-    let input = crate::util::get_puzzle_input(2022, 9);
-    let p1 = part1(&input);
-    println!("p1: {}", p1);
-    let p2 = part2(&input);
-    println!("p2: {}", p2);
+use crate::point::{Direction, P2};
+
+pub fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2022, 9))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 fn parse(input: &str) -> Vec<(Direction, u8)> {
@@ -14,7 +17,7 @@ fn parse(input: &str) -> Vec<(Direction, u8)> {
         .map(|s| {
             let mut it = s.split_ascii_whitespace();
             let dir_str = it.next().unwrap();
-            let dir = Direction::new(dir_str.chars().next().unwrap());
+            let dir = Direction::from_char(dir_str.chars().next().unwrap()).unwrap();
             let dist = it.next().unwrap().parse::<u8>().unwrap();
             (dir, dist)
         })
@@ -32,66 +35,26 @@ fn part2(input: &str) -> usize {
 }
 
 fn solve<const N: usize>(moves: Vec<(Direction, u8)>) -> usize {
-    let mut points = [Point::new(0, 0); N];
+    let mut points = [P2::ORIGIN; N];
     moves
         .iter()
-        .flat_map(|&(direction, amount)| std::iter::repeat(direction).take(amount as usize))
+        .flat_map(|&(direction, amount)| std::iter::repeat_n(direction, amount as usize))
         .map(|direction| {
-            points[0].move_head(direction);
-            (0..N - 1).for_each(|i| points[i + 1].follow(points[i]));
+            points[0] += direction.offset();
+            (0..N - 1).for_each(|i| points[i + 1] = follow(points[i + 1], points[i]));
             points[N - 1]
         })
         .collect::<HashSet<_>>()
         .len()
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    R,
-    U,
-    L,
-    D,
-}
-
-impl Direction {
-    fn new(c: char) -> Self {
-        match c {
-            'R' => Self::R,
-            'U' => Self::U,
-            'L' => Self::L,
-            'D' => Self::D,
-            _ => panic!("Invalid direction"),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl Point {
-    fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-    fn move_head(&mut self, direction: Direction) {
-        let diff = match direction {
-            Direction::R => (1, 0),
-            Direction::U => (0, 1),
-            Direction::L => (-1, 0),
-            Direction::D => (0, -1),
-        };
-        self.x += diff.0;
-        self.y += diff.1;
-    }
-    fn follow(&mut self, head: Point) {
-        let diff = (head.x - self.x, head.y - self.y);
-        let touching = diff.0.abs() < 2 && diff.1.abs() < 2;
-        if !touching {
-            self.x += diff.0.signum();
-            self.y += diff.1.signum();
-        }
+/// Where a knot moves to catch up with the one ahead of it
+fn follow(knot: P2, head: P2) -> P2 {
+    let touching = knot.chebyshev(head) < 2;
+    if touching {
+        knot
+    } else {
+        knot + (head - knot).signum()
     }
 }
 
@@ -101,21 +64,21 @@ mod tests {
 
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2022_9_test.in");
+        let input = crate::util::read_file("inputs/2022_9_test.in").unwrap();
         let p1 = part1(&input);
         assert_eq!(p1, 13);
     }
 
     #[test]
     fn test_2_1() {
-        let input = crate::util::read_file("inputs/2022_9_test.in");
+        let input = crate::util::read_file("inputs/2022_9_test.in").unwrap();
         let p2 = part2(&input);
         assert_eq!(p2, 1);
     }
 
     #[test]
     fn test_2_2() {
-        let input = crate::util::read_file("inputs/2022_9_test_2.in");
+        let input = crate::util::read_file("inputs/2022_9_test_2.in").unwrap();
         let p2 = part2(&input);
         assert_eq!(p2, 36);
     }
@@ -1,9 +1,11 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2025, 1);
-    let p1 = part1(&input);
-    println!("part 1: {}", p1);
-    let p2 = part2(&input);
-    println!("part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2025, 1))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 struct Rotation {
@@ -26,7 +28,7 @@ impl Rotation {
         };
         let count = match self.direction {
             Direction::Left => (current_nb - 1).div_euclid(100) - (res - 1).div_euclid(100),
-            Direction::Right => res.div_euclid(100), 
+            Direction::Right => res.div_euclid(100),
         };
 
         res = res.rem_euclid(100);
@@ -72,27 +74,30 @@ impl Lock {
                 *state = r.apply(*state);
                 Some(*state)
             })
-            .filter(|x| *x == 0).count()
+            .filter(|x| *x == 0)
+            .count()
     }
 
     fn apply_rotations_with_count(&self, starting_nb: isize) -> usize {
-        self.rotations.iter().scan(starting_nb, | state, r | {
-            let res = r.apply_with_pass_count(*state);
-            *state = res.0;
-            Some(res.1)
-        }).sum::<isize>() as usize
+        self.rotations
+            .iter()
+            .scan(starting_nb, |state, r| {
+                let res = r.apply_with_pass_count(*state);
+                *state = res.0;
+                Some(res.1)
+            })
+            .sum::<isize>() as usize
     }
 }
 
 fn part1(input: &str) -> usize {
-    let all_rotations = Lock::from_input(&input);
+    let all_rotations = Lock::from_input(input);
     all_rotations.apply_rotations(50)
 }
 
 fn part2(input: &str) -> usize {
-    let all_rotations = Lock::from_input(&input);
+    let all_rotations = Lock::from_input(input);
     all_rotations.apply_rotations_with_count(50)
-    
 }
 
 #[cfg(test)]
@@ -100,14 +105,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2025_1_test.in");
+        let input = crate::util::read_file("inputs/2025_1_test.in").unwrap();
         let p1 = part1(&input);
         assert_eq!(p1, 3);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2025_1_test.in");
+        let input = crate::util::read_file("inputs/2025_1_test.in").unwrap();
         let p2 = part2(&input);
         assert_eq!(p2, 6);
     }
@@ -1,9 +1,11 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2025, 2);
-    let p1 = part1(&input);
-    println!("part 1: {}", p1);
-    let p2 = part2(&input);
-    println!("part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2025, 2))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 fn part1(input: &str) -> usize {
@@ -18,9 +20,7 @@ fn part2(input: &str) -> usize {
     let product_ranges = get_product_ranges(input);
     product_ranges
         .iter()
-        .map(|(start, end)| {
-            (*start..=*end).filter(|n| p2_invalid(*n)).sum::<usize>()
-        })
+        .map(|(start, end)| (*start..=*end).filter(|n| p2_invalid(*n)).sum::<usize>())
         .sum()
 }
 
@@ -38,7 +38,7 @@ fn get_product_ranges(input: &str) -> Vec<(usize, usize)> {
 fn p1_invalid(n: usize) -> bool {
     let s = n.to_string();
     let total_length = s.len();
-    if total_length % 2 != 0 {
+    if !total_length.is_multiple_of(2) {
         return false;
     }
     let half = total_length / 2;
@@ -50,7 +50,7 @@ fn p2_invalid(n: usize) -> bool {
     let total_length = s.len();
     let half_length = total_length / 2;
     for length in 1..=half_length {
-        if total_length % length != 0 {
+        if !total_length.is_multiple_of(length) {
             continue;
         }
         let repeat = total_length / length;
@@ -66,14 +66,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2025_2_test.in");
+        let input = crate::util::read_file("inputs/2025_2_test.in").unwrap();
         let p1 = part1(&input);
         assert_eq!(p1, 1227775554);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2025_2_test.in");
+        let input = crate::util::read_file("inputs/2025_2_test.in").unwrap();
         let p2 = part2(&input);
         assert_eq!(p2, 4174379265);
     }
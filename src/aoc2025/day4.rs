@@ -1,89 +1,50 @@
-const NEIGHBOURS: [(isize, isize); 8] = [
-    (-1, 0),
-    (1, 0),
-    (0, -1),
-    (0, 1),
-    (-1, -1),
-    (-1, 1),
-    (1, -1),
-    (1, 1),
-];
+use crate::util::Error;
+use year_lib::{Solution, Timer};
 
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2025, 4);
-    let mut grid = Grid::parse(&input);
-    let p1 = part1(&grid);
-    println!("part 1: {}", p1);
-    let p2 = part2(&mut grid);
-    println!("part 2: {}", p2);
+use crate::grid::Grid;
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2025, 4))?;
+    let mut grid = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&grid));
+    let p2 = timer.part2(|| part2(&mut grid));
+    Ok(Solution::new(p1, p2))
 }
 
-fn part1(grid: &Grid) -> usize {
-    grid.get_accessible().len()
+fn part1(grid: &Grid<bool>) -> usize {
+    get_accessible(grid).len()
 }
 
-fn part2(grid: &mut Grid) -> usize {
+fn part2(grid: &mut Grid<bool>) -> usize {
     // turn accessible cells to false until no more accessible cells
     let mut count = 0;
     loop {
-        let accessible_cells = grid.get_accessible();
+        let accessible_cells = get_accessible(grid);
         if accessible_cells.is_empty() {
             break;
         }
-        for (x, y) in accessible_cells {
-            grid.cells[y][x] = false;
+        for pos in accessible_cells {
+            grid[pos] = false;
             count += 1;
         }
     }
     count
 }
 
-struct Grid {
-    cells: Vec<Vec<bool>>,
+fn parse(input: &str) -> Grid<bool> {
+    Grid::parse(input, |c| match c {
+        '@' => true,
+        '.' => false,
+        _ => panic!("invalid char"),
+    })
 }
 
-impl Grid {
-    fn parse(input: &str) -> Self {
-        Grid {
-            cells: input
-                .lines()
-                .map(|line| {
-                    line.bytes()
-                        .map(|b| match b {
-                            b'@' => true,
-                            b'.' => false,
-                            _ => panic!("invalid char"),
-                        })
-                        .collect()
-                })
-                .collect(),
-        }
-    }
-
-    // true cells with fewer than 4 true neighbors (all 8) are accessible
-    fn get_accessible(&self) -> Vec<(usize, usize)> {
-        let mut accessible_cells = Vec::new();
-        for y in 0..self.cells.len() {
-            for x in 0..self.cells[0].len() {
-                if self.cells[y][x]
-                    && NEIGHBOURS
-                        .into_iter()
-                        .filter(|&(dx, dy)| {
-                            self.cells
-                                .get(y.wrapping_add_signed(dy))
-                                .and_then(|row| row.get(x.wrapping_add_signed(dx)))
-                                .filter(|&c| *c)
-                                .is_some()
-                        })
-                        .count()
-                        < 4
-                {
-                    accessible_cells.push((x, y));
-                }
-            }
-        }
-        accessible_cells
-    }
+// true cells with fewer than 4 true neighbors (all 8) are accessible
+fn get_accessible(grid: &Grid<bool>) -> Vec<(usize, usize)> {
+    grid.iter()
+        .filter(|&(pos, &cell)| cell && grid.neighbours8(pos).filter(|&n| grid[n]).count() < 4)
+        .map(|(pos, _)| pos)
+        .collect()
 }
 
 #[cfg(test)]
@@ -91,16 +52,16 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2025_4_test.in");
-        let grid = Grid::parse(&input);
+        let input = crate::util::read_file("inputs/2025_4_test.in").unwrap();
+        let grid = parse(&input);
         let p1 = part1(&grid);
         assert_eq!(p1, 13);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2025_4_test.in");
-        let mut grid = Grid::parse(&input);
+        let input = crate::util::read_file("inputs/2025_4_test.in").unwrap();
+        let mut grid = parse(&input);
         let p1 = part2(&mut grid);
         assert_eq!(p1, 43);
     }
@@ -1,20 +1,20 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2025, 5);
-    let (ranges, values) = parse_input(&input);
-    let p1 = part1(&ranges, &values);
-    println!("part 1: {}", p1);
-    let p2 = part2(&ranges);
-    println!("part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2025, 5))?;
+    let (ranges, values) = timer.parse(|| parse_input(&input));
+    let p1 = timer.part1(|| part1(&ranges, &values));
+    let p2 = timer.part2(|| part2(&ranges));
+    Ok(Solution::new(p1, p2))
 }
 
 fn part1(ranges: &[std::ops::Range<usize>], values: &[usize]) -> usize {
-    // optimised with binary search 
+    // optimised with binary search
     let fn_position = |id| values.binary_search(&id).unwrap_or_else(|e| e);
     ranges
         .iter()
-        .map(|range| {
-            fn_position(range.end) - fn_position(range.start)
-        })
+        .map(|range| fn_position(range.end) - fn_position(range.start))
         .sum()
 }
 
@@ -63,7 +63,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2025_5_test.in");
+        let input = crate::util::read_file("inputs/2025_5_test.in").unwrap();
         let (ranges, values) = parse_input(&input);
         let p1 = part1(&ranges, &values);
         assert_eq!(p1, 3);
@@ -71,7 +71,7 @@ mod tests {
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2025_5_test.in");
+        let input = crate::util::read_file("inputs/2025_5_test.in").unwrap();
         let (ranges, _values) = parse_input(&input);
         let p2 = part2(&ranges);
         assert_eq!(p2, 14);
@@ -1,9 +1,13 @@
-pub(crate) fn run() {
-    let input = crate::util::get_puzzle_input(2025, 6);
-    let p1 = part1(&input);
-    println!("part 1: {}", p1);
-    let p2 = part2(&input);
-    println!("part 2: {}", p2);
+use crate::util::Error;
+use year_lib::{Solution, Timer};
+
+use crate::grid::Grid;
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| crate::util::get_puzzle_input(2025, 6))?;
+    let p1 = timer.part1(|| part1(&input));
+    let p2 = timer.part2(|| part2(&input));
+    Ok(Solution::new(p1, p2))
 }
 
 fn part1(input: &str) -> usize {
@@ -45,29 +49,24 @@ fn parse_input_naive(input: &str) -> Vec<Batch> {
 
 // quick dirty solution using transposition of the input grid
 fn parse_input_transpose(input: &str) -> Vec<Batch> {
-    let line_len = input.lines().next().unwrap().len();
-    let mut line_iterator = input
+    let lines = input
         .lines()
         .take(input.lines().count() - 1)
-        .map(|n| n.chars().into_iter())
-        .collect::<Vec<_>>();
-
-    let transposed = (0..line_len)
-        .map(|_| {
-            line_iterator
-                .iter_mut()
-                .map(|n| n.next().unwrap())
-                .collect::<Vec<_>>()
-        })
+        .map(str::as_bytes)
         .collect::<Vec<_>>();
+    let line_len = lines.iter().map(|line| line.len()).max().unwrap();
+    let digits = Grid::from_fn(line_len, lines.len(), |(x, y)| {
+        lines[y].get(x).map_or(' ', |&b| b as char)
+    });
+    let transposed = digits.transpose();
 
     // read transposed grid and construct batches
     let nb_batches = input.lines().next().unwrap().split_whitespace().count();
     let mut batches = vec![Batch::new_empty(); nb_batches];
     let mut current_batch_idx = 0;
 
-    for row in transposed {
-        let line: String = row.into_iter().collect();
+    for row in transposed.rows() {
+        let line: String = row.iter().collect();
         if line.trim().is_empty() {
             current_batch_idx += 1;
         } else {
@@ -125,14 +124,14 @@ mod tests {
     use super::*;
     #[test]
     fn test_1() {
-        let input = crate::util::read_file("inputs/2025_6_test.in");
+        let input = crate::util::read_file("inputs/2025_6_test.in").unwrap();
         let p1 = part1(&input);
         assert_eq!(p1, 4277556);
     }
 
     #[test]
     fn test_2() {
-        let input = crate::util::read_file("inputs/2025_6_test.in");
+        let input = crate::util::read_file("inputs/2025_6_test.in").unwrap();
         let p2 = part2(&input);
         assert_eq!(p2, 3263827);
     }
@@ -0,0 +1,545 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use year_lib::{DayRun, Timings};
+
+use crate::{util::Error, Year};
+
+/// Min, median and max of the samples of a phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+pub struct DayBench {
+    pub day: u32,
+    pub input: Stats,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    fn new(day: u32, samples: &[Timings]) -> Self {
+        let stats =
+            |phase: fn(&Timings) -> Duration| Stats::new(samples.iter().map(phase).collect());
+        Self {
+            day,
+            input: stats(|t| t.input),
+            parse: stats(|t| t.parse),
+            part1: stats(|t| t.part1),
+            part2: stats(|t| t.part2),
+        }
+    }
+
+    /// Median cost of solving the day, input loading excluded
+    pub fn cost(&self) -> Duration {
+        self.parse.median + self.part1.median + self.part2.median
+    }
+}
+
+/// Runs the day, or the given variant of it, `repeat` times and collects the statistics of every
+/// phase, along with the last run
+pub fn run(
+    aoc_year: &Year,
+    day: u32,
+    variant: Option<&str>,
+    repeat: usize,
+) -> Result<(DayBench, DayRun), Error> {
+    let run = || match variant {
+        Some(variant) => aoc_year.run_variant(day, variant),
+        None => aoc_year.run_day(day),
+    };
+    let runs = (0..repeat.max(1))
+        .map(|_| run().expect("Day is not available"))
+        .collect::<Result<Vec<_>, Error>>()?;
+    let samples = runs.iter().map(|run| run.timings).collect::<Vec<_>>();
+    Ok((
+        DayBench::new(day, &samples),
+        runs.into_iter().last().unwrap(),
+    ))
+}
+
+pub fn report(year: i32, benches: &[DayBench], repeat: usize) {
+    for bench in benches {
+        println!("{} day {} ({} runs)", year, bench.day, repeat.max(1));
+        println!(
+            "{:<8}{:>12}{:>12}{:>12}",
+            "phase", "min us", "median us", "max us"
+        );
+        for (name, stats) in [
+            ("input", &bench.input),
+            ("parse", &bench.parse),
+            ("part 1", &bench.part1),
+            ("part 2", &bench.part2),
+        ] {
+            println!(
+                "{:<8}{:>12}{:>12}{:>12}",
+                name,
+                stats.min.as_micros(),
+                stats.median.as_micros(),
+                stats.max.as_micros()
+            );
+        }
+        println!();
+    }
+    if benches.len() > 1 {
+        let mut sorted = benches.iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|b| std::cmp::Reverse(b.cost()));
+        println!("{} summary (median, sorted by cost)", year);
+        println!(
+            "{:<6}{:>12}{:>12}{:>12}{:>12}",
+            "day", "parse us", "part 1 us", "part 2 us", "total us"
+        );
+        for bench in sorted {
+            println!(
+                "{:<6}{:>12}{:>12}{:>12}{:>12}",
+                bench.day,
+                bench.parse.median.as_micros(),
+                bench.part1.median.as_micros(),
+                bench.part2.median.as_micros(),
+                bench.cost().as_micros()
+            );
+        }
+    }
+}
+
+/// Median of a phase of a benchmarked day, as kept in the history file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub time: u64,
+    /// `git describe` of the tree that was benchmarked
+    pub commit: String,
+    pub machine: String,
+    /// Free-form name of the run, e.g. the alternative implementation that was benchmarked
+    pub label: String,
+    /// Variant of the day that was benchmarked, empty for the day itself
+    pub variant: String,
+    pub year: i32,
+    pub day: u32,
+    /// `parse`, `part1` or `part2`
+    pub phase: String,
+    pub median: Duration,
+    pub runs: usize,
+}
+
+/// Year, day, variant and phase of an entry, what the benchmarks are compared by
+type Key<'a> = (i32, u32, &'a str, &'a str);
+
+impl Entry {
+    fn key(&self) -> Key<'_> {
+        (self.year, self.day, &self.variant, &self.phase)
+    }
+
+    /// Entries of the parse and part phases of the benchmarked days
+    fn of_benches(run: &Entry, year: i32, benches: &[DayBench], repeat: usize) -> Vec<Entry> {
+        benches
+            .iter()
+            .flat_map(|bench| {
+                [
+                    ("parse", bench.parse),
+                    ("part1", bench.part1),
+                    ("part2", bench.part2),
+                ]
+                .map(|(phase, stats)| Entry {
+                    year,
+                    day: bench.day,
+                    phase: phase.to_string(),
+                    median: stats.median,
+                    runs: repeat.max(1),
+                    ..run.clone()
+                })
+            })
+            .collect()
+    }
+}
+
+/// Past benchmarks, one tab separated entry per line:
+/// `time commit machine label year day phase median_ns runs variant`. The variant was added last,
+/// older lines without it benchmarked the days themselves.
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl History {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match path.exists() {
+            true => Self::parse(&crate::util::read_file(&path)?).map_err(|message| {
+                Error::InvalidFile {
+                    path: path.clone(),
+                    message,
+                }
+            })?,
+            false => Vec::new(),
+        };
+        Ok(Self { path, entries })
+    }
+
+    fn parse(contents: &str) -> Result<Vec<Entry>, String> {
+        contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| Self::parse_entry(line).map_err(|e| format!("line {}: {}", i + 1, e)))
+            .collect()
+    }
+
+    fn parse_entry(line: &str) -> Result<Entry, String> {
+        fn number<T: std::str::FromStr>(name: &str, field: &str) -> Result<T, String> {
+            field
+                .parse()
+                .map_err(|_| format!("invalid {} `{}`", name, field))
+        }
+        let mut fields = line.splitn(10, '\t');
+        let mut next = |name: &str| fields.next().ok_or_else(|| format!("missing {}", name));
+        Ok(Entry {
+            time: number("time", next("time")?)?,
+            commit: next("commit")?.to_string(),
+            machine: next("machine")?.to_string(),
+            label: next("label")?.to_string(),
+            year: number("year", next("year")?)?,
+            day: number("day", next("day")?)?,
+            phase: next("phase")?.to_string(),
+            median: Duration::from_nanos(number("median", next("median")?)?),
+            runs: number("runs", next("runs")?)?,
+            variant: fields.next().unwrap_or_default().to_string(),
+        })
+    }
+
+    fn record(&mut self, entries: Vec<Entry>) -> Result<(), Error> {
+        let io_error = |source| Error::Io {
+            path: self.path.clone(),
+            source,
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        for entry in &entries {
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                entry.time,
+                entry.commit,
+                entry.machine,
+                entry.label,
+                entry.year,
+                entry.day,
+                entry.phase,
+                entry.median.as_nanos(),
+                entry.runs,
+                entry.variant
+            )
+            .map_err(io_error)?;
+        }
+        self.entries.extend(entries);
+        Ok(())
+    }
+
+    /// Latest entry of every phase of every variant benchmarked on the machine with `baseline` as
+    /// label, or at a commit starting with `baseline`. Labelled runs and variants only match their
+    /// label, so that a commit stands for the days themselves.
+    fn baseline(&self, baseline: &str, machine: &str) -> BTreeMap<Key<'_>, &Entry> {
+        let at_commit = |e: &Entry| {
+            e.label.is_empty() && e.variant.is_empty() && e.commit.starts_with(baseline)
+        };
+        self.entries
+            .iter()
+            .filter(|e| e.machine == machine)
+            .filter(|e| e.label == baseline || at_commit(e))
+            .map(|e| (e.key(), e))
+            .collect()
+    }
+}
+
+/// A phase that got slower than the baseline by more than the threshold, in percent
+fn regressions<'a>(
+    baseline: &BTreeMap<Key<'_>, &Entry>,
+    current: &'a [Entry],
+    threshold: f64,
+) -> Vec<(&'a Entry, Duration)> {
+    current
+        .iter()
+        .filter_map(|e| {
+            let before = baseline.get(&e.key())?.median;
+            let limit = before.as_secs_f64() * (1.0 + threshold / 100.0);
+            (e.median.as_secs_f64() > limit).then_some((e, before))
+        })
+        .collect()
+}
+
+/// `git describe` of the repository, marked dirty if it has uncommitted changes
+fn commit() -> String {
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Host name, CPU model and number of threads
+fn machine() -> String {
+    let host = fs::read_to_string("/etc/hostname")
+        .ok()
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .map(|host| host.trim().to_string())
+        .filter(|host| !host.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+    let cpu = fs::read_to_string("/proc/cpuinfo")
+        .ok()
+        .and_then(|cpuinfo| {
+            cpuinfo
+                .lines()
+                .find(|line| line.starts_with("model name"))
+                .and_then(|line| line.split_once(':'))
+                .map(|(_, model)| model.trim().to_string())
+        })
+        .unwrap_or_else(|| std::env::consts::ARCH.to_string());
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    format!("{} ({}, {} threads)", host, cpu, threads).replace('\t', " ")
+}
+
+/// Saves the benchmarks in the history, and compares them with the `baseline` commit or label
+/// when given. Returns false if a phase got slower than the baseline by more than `threshold`
+/// percent.
+pub fn save(
+    year: i32,
+    benches: &[DayBench],
+    repeat: usize,
+    variant: Option<&str>,
+    label: Option<&str>,
+    baseline: Option<&str>,
+    threshold: f64,
+) -> bool {
+    let path = match crate::util::cache_root() {
+        Ok(root) => root.join("bench.history"),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+    let mut history = match History::load(&path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+    let run = Entry {
+        time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        commit: commit(),
+        machine: machine(),
+        label: label.unwrap_or_default().replace('\t', " "),
+        variant: variant.unwrap_or_default().to_string(),
+        year,
+        day: 0,
+        phase: String::new(),
+        median: Duration::ZERO,
+        runs: 0,
+    };
+    let entries = Entry::of_benches(&run, year, benches, repeat);
+    let success = match baseline {
+        Some(baseline) => compare(&history, baseline, &run.machine, &entries, threshold),
+        None => true,
+    };
+    if let Err(e) = history.record(entries) {
+        eprintln!("error: {}", e);
+        return false;
+    }
+    println!("saved to {} ({})", path.display(), run.commit);
+    success
+}
+
+fn compare(
+    history: &History,
+    baseline: &str,
+    machine: &str,
+    current: &[Entry],
+    threshold: f64,
+) -> bool {
+    let baseline_entries = history.baseline(baseline, machine);
+    if baseline_entries.is_empty() {
+        eprintln!("error: no benchmark of {} on this machine", baseline);
+        return false;
+    }
+    let regressions = regressions(&baseline_entries, current, threshold);
+    if regressions.is_empty() {
+        println!(
+            "no phase slower than {} by more than {}%",
+            baseline, threshold
+        );
+        return true;
+    }
+    println!("slower than {} by more than {}%", baseline, threshold);
+    println!(
+        "{:<6}{:<8}{:>12}{:>12}{:>10}",
+        "day", "phase", "before us", "now us", "change"
+    );
+    for (entry, before) in regressions {
+        println!(
+            "{:<6}{:<8}{:>12}{:>12}{:>9.0}%",
+            entry.day,
+            entry.phase,
+            before.as_micros(),
+            entry.median.as_micros(),
+            (entry.median.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0
+        );
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 3].map(Duration::from_micros).to_vec();
+        let stats = Stats::new(samples);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.max, Duration::from_micros(5));
+
+        let samples = [4, 1, 2, 8].map(Duration::from_micros).to_vec();
+        let stats = Stats::new(samples);
+        assert_eq!(stats.median, Duration::from_micros(3));
+    }
+
+    fn entry(commit: &str, label: &str, day: u32, phase: &str, median: u64) -> Entry {
+        Entry {
+            time: 1,
+            commit: commit.to_string(),
+            machine: "host (cpu, 4 threads)".to_string(),
+            label: label.to_string(),
+            year: 2020,
+            day,
+            phase: phase.to_string(),
+            median: Duration::from_micros(median),
+            runs: 10,
+            variant: String::new(),
+        }
+    }
+
+    fn variant_entry(commit: &str, variant: &str, day: u32, phase: &str, median: u64) -> Entry {
+        Entry {
+            variant: variant.to_string(),
+            ..entry(commit, variant, day, phase, median)
+        }
+    }
+
+    #[test]
+    fn test_history() {
+        let path = std::env::temp_dir().join(format!("aoc_bench_{}.history", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let mut history = History::load(&path).unwrap();
+        history
+            .record(vec![
+                entry("abc1234", "", 15, "part2", 100),
+                entry("abc1234", "", 8, "part2", 100),
+            ])
+            .unwrap();
+        history
+            .record(vec![
+                variant_entry("abc1234", "vec", 15, "part2", 50),
+                entry("abc1234", "fast", 15, "part2", 80),
+                entry("def5678-dirty", "fast", 15, "part2", 90),
+                variant_entry("def5678-dirty", "map", 15, "part2", 70),
+                Entry {
+                    label: "fast".to_string(),
+                    ..variant_entry("def5678-dirty", "map", 15, "part2", 60)
+                },
+            ])
+            .unwrap();
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.entries.len(), 7);
+        assert_eq!(
+            history.entries[2],
+            variant_entry("abc1234", "vec", 15, "part2", 50)
+        );
+        fs::remove_file(&path).unwrap();
+
+        let machine = "host (cpu, 4 threads)";
+        // the labelled runs and the variants at the commit are not the days themselves
+        let baseline = history.baseline("abc", machine);
+        assert_eq!(baseline.len(), 2);
+        assert!(baseline
+            .values()
+            .all(|e| e.median == Duration::from_micros(100)));
+        assert_eq!(history.baseline("vec", machine).len(), 1);
+        // a label benchmarked with and without a variant keeps both
+        let fast = history.baseline("fast", machine);
+        assert_eq!(fast.len(), 2);
+        assert_eq!(
+            fast[&(2020, 15, "", "part2")].median,
+            Duration::from_micros(90)
+        );
+        assert_eq!(
+            fast[&(2020, 15, "map", "part2")].median,
+            Duration::from_micros(60)
+        );
+        assert!(history.baseline("abc", "other").is_empty());
+
+        let baseline = history.baseline("abc", machine);
+        let current = [
+            entry("ghi9012", "", 15, "part2", 120),
+            entry("ghi9012", "", 8, "part2", 105),
+            entry("ghi9012", "", 8, "part1", 500),
+        ];
+        let regressions = regressions(&baseline, &current, 10.0);
+        assert_eq!(regressions, vec![(&current[0], Duration::from_micros(100))]);
+    }
+
+    #[test]
+    fn test_parse_history_errors() {
+        let line = "1\tabc1234\thost\t\t2020\t15\tpart2\t100000\t10";
+        assert_eq!(History::parse(line).unwrap()[0].variant, "");
+        let entries = History::parse(&format!("{}\tvec", line)).unwrap();
+        assert_eq!(entries[0].variant, "vec");
+        assert_eq!(
+            History::parse(&format!("{}\n\n1\tabc1234\thost", line)),
+            Err("line 3: missing label".to_string())
+        );
+        assert_eq!(
+            History::parse(&line.replace("2020", "twenty")),
+            Err("line 1: invalid year `twenty`".to_string())
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use std::{collections::BTreeMap, fmt, path::Path};
+
+use year_lib::{Answer, DayRun};
+
+use crate::util::Error;
+
+/// Known answers of a year, read from `answers/{year}.toml` of the repository.
+///
+/// ```toml
+/// [day1]
+/// part1 = 1477
+/// part2 = "multi-line answers use '''literal strings'''"
+/// ```
+#[derive(Debug, Default)]
+pub struct KnownAnswers(BTreeMap<(u32, u8), Answer>);
+
+impl KnownAnswers {
+    pub fn load(year: i32) -> Result<Self, Error> {
+        let filename = format!("{}/answers/{}.toml", env!("CARGO_MANIFEST_DIR"), year);
+        if Path::new(&filename).exists() {
+            Self::parse(&crate::util::read_file(&filename)?).map_err(|message| Error::InvalidFile {
+                path: filename.into(),
+                message,
+            })
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub(crate) fn parse(contents: &str) -> Result<Self, String> {
+        let table = contents
+            .parse::<toml::Table>()
+            .map_err(|e| e.to_string().trim_end().to_string())?;
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u32>().ok())
+                .ok_or_else(|| format!("invalid day key `{}`", day_key))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("`{}` is not a table", day_key))?;
+            for (part_key, value) in parts {
+                let part = part_key
+                    .strip_prefix("part")
+                    .and_then(|part| part.parse::<u8>().ok())
+                    .ok_or_else(|| format!("invalid part key `{}.{}`", day_key, part_key))?;
+                let answer = match value {
+                    toml::Value::Integer(n) => Answer::Number(*n as i128),
+                    toml::Value::String(s) => Answer::Text(s.clone()),
+                    _ => {
+                        return Err(format!(
+                            "`{}.{}` is neither an integer nor a string",
+                            day_key, part_key
+                        ))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Self(answers))
+    }
+
+    pub fn get(&self, day: u32, part: u8) -> Option<&Answer> {
+        self.0.get(&(day, part))
+    }
+
+    /// Whether a part of the day has a known answer
+    pub fn has_day(&self, day: u32) -> bool {
+        [1, 2].iter().any(|&part| self.get(day, part).is_some())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+/// Compares an answer with the known one, ignoring trailing whitespace of text answers.
+pub fn status(answer: &Answer, known: Option<&Answer>) -> Status {
+    match known {
+        None => Status::Unknown,
+        Some(known) if normalize(answer) == normalize(known) => Status::Pass,
+        Some(_) => Status::Fail,
+    }
+}
+
+fn normalize(answer: &Answer) -> String {
+    answer
+        .to_string()
+        .lines()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Prints a PASS/FAIL/UNKNOWN line for every part of the day and returns false if any part failed.
+pub fn report(year: i32, run: &DayRun, known_answers: &KnownAnswers) -> bool {
+    let day = run.day;
+    let mut success = true;
+    for (part, answer) in run.solution.parts() {
+        let known = known_answers.get(day, part);
+        if *answer == Answer::Unsolved && known.is_none() {
+            continue;
+        }
+        let status = status(answer, known);
+        match (status, known) {
+            (Status::Fail, Some(known)) => {
+                success = false;
+                println!(
+                    "{} day {} part {}: {} (expected {}, got {})",
+                    year, day, part, status, known, answer
+                );
+            }
+            _ => println!("{} day {} part {}: {}", year, day, part, status),
+        }
+    }
+    success
+}
+
+/// First part whose answer the variant gets wrong, with the answer of the day and of the variant
+pub fn variant_mismatch<'a>(
+    run: &'a DayRun,
+    variant_run: &'a DayRun,
+) -> Option<(u8, &'a Answer, &'a Answer)> {
+    run.solution
+        .parts()
+        .into_iter()
+        .zip(variant_run.solution.parts())
+        .find(|((_, expected), (_, answer))| status(answer, Some(expected)) == Status::Fail)
+        .map(|((part, expected), (_, answer))| (part, expected, answer))
+}
+
+/// Prints a PASS/FAIL line for a variant of the day and returns false if it does not give the
+/// answers of the day.
+pub fn report_variant(year: i32, run: &DayRun, variant: &str, variant_run: &DayRun) -> bool {
+    let day = run.day;
+    match variant_mismatch(run, variant_run) {
+        Some((part, expected, answer)) => {
+            println!(
+                "{} day {} variant {} part {}: {} (expected {}, got {})",
+                year,
+                day,
+                variant,
+                part,
+                Status::Fail,
+                expected,
+                answer
+            );
+            false
+        }
+        None => {
+            println!("{} day {} variant {}: {}", year, day, variant, Status::Pass);
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let known = KnownAnswers::parse(
+            "[day1]\npart1 = 1477\npart2 = \"abc\"\n\n[day13]\npart2 = '''\n#..\n.#.'''\n",
+        )
+        .unwrap();
+        assert_eq!(known.get(1, 1), Some(&Answer::Number(1477)));
+        assert_eq!(known.get(1, 2), Some(&Answer::Text("abc".to_string())));
+        assert_eq!(
+            known.get(13, 2),
+            Some(&Answer::Text("#..\n.#.".to_string()))
+        );
+        assert_eq!(known.get(13, 1), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = KnownAnswers::parse("[day1]\npart1 = \n").unwrap_err();
+        assert!(error.contains("line 2"), "{}", error);
+        assert_eq!(
+            KnownAnswers::parse("[dayone]\npart1 = 1\n").unwrap_err(),
+            "invalid day key `dayone`"
+        );
+        assert_eq!(
+            KnownAnswers::parse("[day1]\npart = 1\n").unwrap_err(),
+            "invalid part key `day1.part`"
+        );
+        assert_eq!(
+            KnownAnswers::parse("[day1]\npart1 = 1.5\n").unwrap_err(),
+            "`day1.part1` is neither an integer nor a string"
+        );
+    }
+
+    #[test]
+    fn test_status() {
+        let known = Answer::Number(42);
+        assert_eq!(status(&Answer::from(42u32), Some(&known)), Status::Pass);
+        assert_eq!(status(&Answer::from(41u32), Some(&known)), Status::Fail);
+        assert_eq!(status(&Answer::from(42u32), None), Status::Unknown);
+        assert_eq!(status(&Answer::Unsolved, Some(&known)), Status::Fail);
+        let text = Answer::Text("#..\n.#.".to_string());
+        assert_eq!(
+            status(&Answer::from("#..\n.#.\n"), Some(&text)),
+            Status::Pass
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! The only way to talk with adventofcode.com: every request identifies the runner, is
+//! authenticated with the session and waits for the previous request to the same host to be
+//! far enough in the past.
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use curl::easy::{Easy2, Handler, TimeCondition, WriteError};
+use lazy_static::lazy_static;
+
+use crate::util::Error;
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/gokberkkocak/adventofcode using libcurl";
+/// Least time between two requests to the same host
+const MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+lazy_static! {
+    /// Time of the last request to each host
+    static ref LAST_REQUESTS: Mutex<HashMap<String, Instant>> = Mutex::new(HashMap::new());
+}
+
+struct Collector(Vec<u8>);
+
+impl Handler for Collector {
+    fn write(&mut self, data: &[u8]) -> Result<usize, WriteError> {
+        self.0.extend_from_slice(data);
+        Ok(data.len())
+    }
+}
+
+/// Returns the body of an authenticated GET request
+pub fn get(url: &str, session: &str) -> Result<String, Error> {
+    let mut easy = Easy2::new(Collector(Vec::new()));
+    easy.get(true)?;
+    easy.url(url)?;
+    perform(easy, url, session)?.ok_or_else(|| unexpected(304, ""))
+}
+
+/// Returns the body of an authenticated GET request, or `None` if the page did not change
+/// since the given time
+pub fn get_if_modified(
+    url: &str,
+    session: &str,
+    since: SystemTime,
+) -> Result<Option<String>, Error> {
+    let mut easy = Easy2::new(Collector(Vec::new()));
+    easy.get(true)?;
+    easy.url(url)?;
+    easy.time_condition(TimeCondition::IfModifiedSince)?;
+    let since = since.duration_since(UNIX_EPOCH).unwrap_or_default();
+    easy.time_value(since.as_secs() as i64)?;
+    perform(easy, url, session)
+}
+
+/// Returns the body of an authenticated POST request of the url-encoded fields
+pub fn post(url: &str, session: &str, fields: &str) -> Result<String, Error> {
+    let mut easy = Easy2::new(Collector(Vec::new()));
+    easy.url(url)?;
+    easy.post(true)?;
+    easy.post_fields_copy(fields.as_bytes())?;
+    perform(easy, url, session)?.ok_or_else(|| unexpected(304, ""))
+}
+
+/// Url-encodes a form value
+pub fn encode(value: &str) -> String {
+    Easy2::new(Collector(Vec::new())).url_encode(value.as_bytes())
+}
+
+/// Performs an authenticated request once the host may be requested again. Returns the body
+/// of a successful response, `None` if the page was not modified.
+fn perform(mut easy: Easy2<Collector>, url: &str, session: &str) -> Result<Option<String>, Error> {
+    easy.cookie(&format!("session={}", session))?;
+    easy.useragent(USER_AGENT)?;
+    throttle(host(url));
+    easy.perform()?;
+    let status = easy.response_code()?;
+    let body = String::from_utf8_lossy(&easy.get_ref().0).into_owned();
+    match status {
+        200 if easy.time_condition_unmet()? => Ok(None),
+        200 => Ok(Some(body)),
+        304 => Ok(None),
+        404 if body.contains("before it unlocks") => Err(Error::NotUnlocked),
+        _ => Err(unexpected(status, &body)),
+    }
+}
+
+fn unexpected(status: u32, body: &str) -> Error {
+    Error::Http {
+        status,
+        message: body.lines().next().unwrap_or_default().trim().to_string(),
+    }
+}
+
+/// Host and port of the url
+fn host(url: &str) -> &str {
+    let url = url.split_once("://").map_or(url, |(_, rest)| rest);
+    url.split('/').next().unwrap_or(url)
+}
+
+/// Waits until the host may be requested again. The time of the request is reserved first, so
+/// that concurrent requests queue up behind it.
+fn throttle(host: &str) {
+    let wait = {
+        let mut last_requests = LAST_REQUESTS.lock().unwrap();
+        let now = Instant::now();
+        let wait = wait_time(last_requests.get(host).copied(), now);
+        last_requests.insert(host.to_string(), now + wait);
+        wait
+    };
+    thread::sleep(wait);
+}
+
+fn wait_time(last_request: Option<Instant>, now: Instant) -> Duration {
+    last_request.map_or(Duration::ZERO, |last| {
+        MIN_INTERVAL.saturating_sub(now.saturating_duration_since(last))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::serve_once;
+
+    #[test]
+    fn test_host() {
+        assert_eq!(
+            host("https://adventofcode.com/2021/day/1"),
+            "adventofcode.com"
+        );
+        assert_eq!(host("http://127.0.0.1:8080"), "127.0.0.1:8080");
+    }
+
+    #[test]
+    fn test_wait_time() {
+        let now = Instant::now();
+        assert_eq!(wait_time(None, now), Duration::ZERO);
+        assert_eq!(wait_time(Some(now), now), MIN_INTERVAL);
+        let later = now + Duration::from_millis(400);
+        assert_eq!(
+            wait_time(Some(now), later),
+            MIN_INTERVAL - Duration::from_millis(400)
+        );
+        assert_eq!(wait_time(Some(now), now + MIN_INTERVAL * 2), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_throttle() {
+        let (base_url, server) = serve_once(200, "first");
+        assert_eq!(get(&base_url, "abc").unwrap(), "first");
+        server.join().unwrap();
+        let start = Instant::now();
+        throttle(host(&base_url));
+        assert!(start.elapsed() >= MIN_INTERVAL / 2);
+    }
+
+    #[test]
+    fn test_get_if_modified() {
+        let (base_url, server) = serve_once(304, "");
+        let since = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        assert_eq!(get_if_modified(&base_url, "abc", since).unwrap(), None);
+        let request = server.join().unwrap();
+        assert!(request.contains("If-Modified-Since: Tue, 14 Nov 2023 22:13:20 GMT\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+
+        let (base_url, server) = serve_once(200, "changed");
+        let page = get_if_modified(&base_url, "abc", since).unwrap();
+        server.join().unwrap();
+        assert_eq!(page.as_deref(), Some("changed"));
+    }
+}
@@ -0,0 +1,445 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    client::{self, AOC_URL},
+    submit::Submissions,
+    util::Error,
+};
+
+lazy_static! {
+    static ref CODE_BLOCK: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]+>").unwrap();
+    /// An article of the puzzle, followed by the answer once the part is solved
+    static ref ARTICLE: Regex = Regex::new(
+        r#"(?s)<article class="day-desc">(.*?)</article>\s*(<p>Your puzzle answer was.*?</p>)?"#
+    )
+    .unwrap();
+    static ref TOKEN: Regex = Regex::new(r#"(?s)<(/?)([a-zA-Z0-9]+)([^>]*)>|([^<]+)"#).unwrap();
+    static ref HREF: Regex = Regex::new(r#"href="([^"]*)""#).unwrap();
+}
+
+/// Width of the paragraphs printed as text
+const WIDTH: usize = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Wrapped text for the terminal
+    Text,
+    Markdown,
+}
+
+/// Puzzle articles of the page, with their answers if solved
+fn articles(page: &str) -> Vec<&str> {
+    ARTICLE
+        .captures_iter(page)
+        .flat_map(|article| [article.get(1), article.get(2)])
+        .flatten()
+        .map(|m| m.as_str())
+        .collect()
+}
+
+/// The page shows the second part once the first one is solved
+fn has_part_two(page: &str) -> bool {
+    ARTICLE.captures_iter(page).count() > 1
+}
+
+/// Renders the puzzle articles of the page
+pub fn render(page: &str, style: Style) -> String {
+    let mut renderer = Renderer::new(style);
+    for article in articles(page) {
+        renderer.render(article);
+    }
+    renderer.finish()
+}
+
+/// Converts the few tags of the puzzle articles: headers, paragraphs, code blocks, lists, inline
+/// code, emphasis and links.
+struct Renderer {
+    style: Style,
+    out: String,
+    /// Text of the current paragraph, header or list item
+    block: String,
+    /// Inside a code block
+    pre: bool,
+    /// Inside inline code, where emphasis cannot be shown
+    code: bool,
+    hrefs: Vec<String>,
+}
+
+impl Renderer {
+    fn new(style: Style) -> Self {
+        Self {
+            style,
+            out: String::new(),
+            block: String::new(),
+            pre: false,
+            code: false,
+            hrefs: Vec::new(),
+        }
+    }
+
+    fn render(&mut self, html: &str) {
+        let markdown = self.style == Style::Markdown;
+        for token in TOKEN.captures_iter(html) {
+            if let Some(text) = token.get(4) {
+                let text = decode_entities(text.as_str());
+                match self.pre {
+                    true => self.block.push_str(&text),
+                    false => self.push_words(&text),
+                }
+                continue;
+            }
+            let closing = !token[1].is_empty();
+            match (&token[2], closing) {
+                ("pre", false) => {
+                    self.flush();
+                    self.pre = true;
+                }
+                ("pre", true) => {
+                    let code = std::mem::take(&mut self.block);
+                    let code = code.trim_end_matches('\n');
+                    match markdown {
+                        true => self.out.push_str(&format!("```\n{}\n```\n\n", code)),
+                        false => {
+                            code.lines()
+                                .for_each(|line| self.out.push_str(&format!("    {}\n", line)));
+                            self.out.push('\n');
+                        }
+                    }
+                    self.pre = false;
+                }
+                ("h2", false) if markdown => self.block.push_str("## "),
+                ("li", false) => {
+                    self.flush();
+                    self.block.push_str("- ");
+                }
+                ("h2" | "p" | "li", true) => self.flush(),
+                ("ul", true) => self.out.push('\n'),
+                ("br", _) => self.block.push('\n'),
+                ("code", _) if markdown && !self.pre => {
+                    self.code = !closing;
+                    self.block.push('`');
+                }
+                ("em", _) if markdown && !self.pre && !self.code => self.block.push_str("**"),
+                ("a", false) if markdown => {
+                    let href = HREF.captures(&token[3]).map(|href| href[1].to_string());
+                    self.hrefs.push(href.unwrap_or_default());
+                    self.block.push('[');
+                }
+                ("a", true) if markdown => {
+                    let href = self.hrefs.pop().unwrap_or_default();
+                    self.block.push_str(&format!("]({})", href));
+                }
+                _ => {}
+            }
+        }
+        self.flush();
+    }
+
+    /// Adds text to the current block, collapsing the whitespace as a browser would
+    fn push_words(&mut self, text: &str) {
+        let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.starts_with(char::is_whitespace) && !self.block.ends_with([' ', '\n']) {
+            self.block.push(' ');
+        }
+        self.block.push_str(&collapsed);
+        if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+            self.block.push(' ');
+        }
+    }
+
+    /// Ends the current block
+    fn flush(&mut self) {
+        let block = std::mem::take(&mut self.block);
+        let block = block.trim();
+        if block.is_empty() {
+            return;
+        }
+        let list_item = block.starts_with("- ");
+        match (self.style, list_item) {
+            (Style::Markdown, _) => self.out.push_str(block),
+            (Style::Text, true) => self.out.push_str(&format!("  {}", wrap(block, WIDTH - 2))),
+            (Style::Text, false) => self.out.push_str(&wrap(block, WIDTH)),
+        }
+        self.out.push_str(if list_item { "\n" } else { "\n\n" });
+    }
+
+    fn finish(mut self) -> String {
+        self.flush();
+        self.out.trim_end().to_string() + "\n"
+    }
+}
+
+/// Wraps the words of the text at `width`, indenting the following lines of list items
+fn wrap(text: &str, width: usize) -> String {
+    let indent = match text.starts_with("- ") {
+        true => "    ",
+        false => "",
+    };
+    let mut lines = vec![String::new()];
+    for word in text.split(' ') {
+        let line = lines.last_mut().unwrap();
+        if !line.trim().is_empty() && line.len() + 1 + word.len() > width {
+            lines.push(format!("{}{}", indent, word));
+        } else {
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+    }
+    lines.join("\n")
+}
+
+fn decode_entities(html: &str) -> String {
+    html.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn html_to_text(html: &str) -> String {
+    decode_entities(&TAG.replace_all(html, ""))
+}
+
+/// Code blocks following a mention of an example in the puzzle articles of the page, or else
+/// the first code block
+pub(crate) fn extract_examples(page: &str) -> Vec<String> {
+    let mut first = None;
+    let mut examples = Vec::new();
+    let articles = ARTICLE
+        .captures_iter(page)
+        .map(|article| article.get(1).unwrap().as_str())
+        .collect::<Vec<_>>();
+    // pages without articles are searched as a whole
+    let articles = match articles.is_empty() {
+        true => vec![page],
+        false => articles,
+    };
+    for article in articles {
+        let mut previous_end = 0;
+        for block in CODE_BLOCK.captures_iter(article) {
+            let whole = block.get(0).unwrap();
+            let code = block.get(1).unwrap().as_str();
+            if article[previous_end..whole.start()]
+                .to_lowercase()
+                .contains("example")
+            {
+                examples.push(html_to_text(code));
+            }
+            first.get_or_insert(code);
+            previous_end = whole.end();
+        }
+    }
+    if examples.is_empty() {
+        examples.extend(first.map(html_to_text));
+    }
+    examples
+}
+
+/// Returns the puzzle page of the day, from the cached one at `path` unless it has to be
+/// refreshed: when asked to, or when it predates the second part of a solved first part. A cached
+/// page is only downloaded again if it changed on the server.
+fn fetch_page(
+    base_url: &str,
+    session: &str,
+    path: &Path,
+    (year, day): (i32, u32),
+    part1_solved: bool,
+    refresh: bool,
+) -> Result<String, Error> {
+    let url = format!("{}/{}/day/{}", base_url, year, day);
+    let cached = match path.exists() {
+        true => Some(crate::util::read_file(path)?),
+        false => None,
+    };
+    let page = match cached {
+        Some(page) if !refresh && (!part1_solved || has_part_two(&page)) => return Ok(page),
+        Some(page) => {
+            let modified = fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .map_err(|source| Error::Io {
+                    path: path.to_path_buf(),
+                    source,
+                })?;
+            match client::get_if_modified(&url, session, modified)? {
+                Some(page) => page,
+                None => return Ok(page),
+            }
+        }
+        None => client::get(&url, session)?,
+    };
+    crate::util::write_to_file(page.as_bytes(), path)?;
+    Ok(page)
+}
+
+/// Saves the examples of the page not saved yet as `{year}_{day}_test_N.in` in `dir`, numbered
+/// after the existing examples. Returns the created files.
+fn save_examples(dir: &Path, page: &str, year: i32, day: u32) -> Result<Vec<PathBuf>, Error> {
+    let existing = match dir.exists() {
+        true => crate::util::example_inputs_in(dir, year, day)?,
+        false => Vec::new(),
+    };
+    let saved = existing
+        .iter()
+        .map(|(_, path)| crate::util::read_file(path))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut next = existing.iter().map(|(n, _)| n + 1).max().unwrap_or(1);
+    let mut created = Vec::new();
+    for example in extract_examples(page) {
+        if saved.iter().any(|saved| saved.trim() == example.trim()) {
+            continue;
+        }
+        let path = dir.join(format!("{}_{}_test_{}.in", year, day, next));
+        crate::util::write_to_file(example.as_bytes(), &path)?;
+        created.push(path);
+        next += 1;
+    }
+    Ok(created)
+}
+
+/// Prints the puzzle description of the day, cached next to the inputs of the account, and
+/// saves its examples in `inputs/`. Returns false if the description could not be fetched.
+pub fn run(year: i32, day: u32, style: Style, refresh: bool) -> bool {
+    let fetched = crate::unlock::check(year, day).and_then(|_| {
+        let session = crate::util::get_session()?;
+        let dir = crate::util::account_dir(&session)?;
+        let submissions = Submissions::load(dir.join(format!("{}_{}.submissions", year, day)))?;
+        let part1_solved = submissions.is_solved(1);
+        let path = dir.join(format!("{}_{}.html", year, day));
+        fetch_page(AOC_URL, &session, &path, (year, day), part1_solved, refresh)
+    });
+    let page = match fetched {
+        Ok(page) => page,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+    print!("{}", render(&page, style));
+    let inputs = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+    match save_examples(&inputs, &page, year, day) {
+        Ok(created) => created
+            .iter()
+            .for_each(|path| eprintln!("created {}", path.display())),
+        Err(e) => eprintln!("warning: examples not saved: {}", e),
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::test_server::serve_once;
+
+    const PART1: &str = include_str!("../fixtures/puzzle_part1.html");
+    const SOLVED: &str = include_str!("../fixtures/puzzle_solved.html");
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_render_text() {
+        let text = render(PART1, Style::Text);
+        assert!(text.starts_with(
+            "--- Day 1: Sample Depths ---\n\n\
+            The elves hand you a list of depth readings & ask how often the depth increases.\n\n"
+        ));
+        assert!(text.contains("report:\n\n    199\n    200\n    208\n    210\n\nCount"));
+        assert!(text.contains(
+            "Count the number of times a depth is larger than the previous one (< means\nsmaller)."
+        ));
+        assert!(text.contains("  - 200 (increased)\n  - 208 (increased)\n\n"));
+        assert!(!text.contains("get your puzzle input"));
+        assert!(!text.contains("Part Two"));
+        assert!(text.ends_with("previous measurement?\n"));
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let markdown = render(SOLVED, Style::Markdown);
+        assert!(markdown.starts_with("## --- Day 1: Sample Depths ---\n\n"));
+        assert!(markdown.contains("a list of **depth readings** &"));
+        assert!(markdown.contains("```\n199\n200\n208\n210\n```\n\n"));
+        assert!(markdown.contains(
+            "(`<` means smaller). In this example, there are `3` such increases. \
+            See [sonar](https://en.wikipedia.org/wiki/Sonar)."
+        ));
+        assert!(markdown.contains("- `200` (**increased**)\n- `208` (**increased**)\n\n"));
+        assert!(markdown.contains("Your puzzle answer was `1477`.\n\n## --- Part Two ---"));
+        assert!(markdown.ends_with("Your puzzle answer was `1523`.\n"));
+        assert!(!markdown.contains("Both parts of this puzzle are complete"));
+    }
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(extract_examples(PART1), vec!["199\n200\n208\n210\n"]);
+        assert_eq!(
+            extract_examples(SOLVED),
+            vec![
+                "199\n200\n208\n210\n",
+                "199  A\n200  A B\n208  A B\n210    B\n",
+                "1\n2\n3\n4\n"
+            ]
+        );
+        assert_eq!(
+            extract_examples("<pre><code>only block</code></pre>"),
+            vec!["only block"]
+        );
+        assert!(extract_examples("<p>no code</p>").is_empty());
+    }
+
+    #[test]
+    fn test_save_examples() {
+        let dir = temp_dir("examples");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("2016_1_test.in"), "199\n200\n208\n210").unwrap();
+        let created = save_examples(&dir, SOLVED, 2016, 1).unwrap();
+        assert_eq!(
+            created,
+            vec![dir.join("2016_1_test_2.in"), dir.join("2016_1_test_3.in")]
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("2016_1_test_3.in")).unwrap(),
+            "1\n2\n3\n4\n"
+        );
+        assert!(save_examples(&dir, SOLVED, 2016, 1).unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_page() {
+        let dir = temp_dir("describe");
+        let path = dir.join("2016_1.html");
+        let (base_url, server) = serve_once(200, PART1);
+        let page = fetch_page(&base_url, "abc", &path, (2016, 1), false, false).unwrap();
+        assert!(server
+            .join()
+            .unwrap()
+            .starts_with("GET /2016/day/1 HTTP/1.1\r\n"));
+        assert!(!has_part_two(&page));
+
+        // cached until the first part is solved
+        let page = fetch_page("http://127.0.0.1:9", "abc", &path, (2016, 1), false, false);
+        assert_eq!(page.unwrap(), PART1.trim());
+        let (base_url, server) = serve_once(200, SOLVED);
+        let page = fetch_page(&base_url, "abc", &path, (2016, 1), true, false).unwrap();
+        server.join().unwrap();
+        assert!(has_part_two(&page));
+        let page = fetch_page("http://127.0.0.1:9", "abc", &path, (2016, 1), true, false);
+        assert!(has_part_two(&page.unwrap()));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,342 @@
+//! Rectangular grids of cells stored row by row, as most puzzle maps are.
+//!
+//! Positions are `(x, y)` pairs, `x` being the column and `y` the row from the top.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// Offsets of the 4 orthogonal neighbours
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// Offsets of the 8 neighbours, the orthogonal ones first
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of the cells given row by row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "cells do not fill the grid");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Self::new(width, height, cells)
+    }
+
+    /// Parses a map of characters, one row per line. Panics if the rows differ in length.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let row_width = cells.len() - len;
+            assert_eq!(
+                *width.get_or_insert(row_width),
+                row_width,
+                "row {} differs in length",
+                height
+            );
+            height += 1;
+        }
+        Self::new(width.unwrap_or(0), height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        (x < self.width && y < self.height).then(|| &mut self.cells[y * self.width + x])
+    }
+
+    /// Cell at the position, wrapping around the edges, `None` if the grid is empty
+    pub fn get_wrapping(&self, (x, y): (isize, isize)) -> Option<&T> {
+        let x = x.checked_rem_euclid(self.width as isize)? as usize;
+        let y = y.checked_rem_euclid(self.height as isize)? as usize;
+        self.get((x, y))
+    }
+
+    /// Position at the offset of the given one, if it is in the grid
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// Position at the offset of the given one, wrapping around the edges, `None` if the grid
+    /// is empty
+    pub fn step_wrapping(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        Some((
+            (x as isize + dx).checked_rem_euclid(self.width as isize)? as usize,
+            (y as isize + dy).checked_rem_euclid(self.height as isize)? as usize,
+        ))
+    }
+
+    /// Orthogonal neighbours of the position in the grid
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&delta| self.step(pos, delta))
+    }
+
+    /// All 8 neighbours of the position in the grid
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&delta| self.step(pos, delta))
+    }
+
+    /// Orthogonal neighbours of the position, wrapping around the edges
+    pub fn neighbours4_wrapping(
+        &self,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&delta| self.step_wrapping(pos, delta))
+    }
+
+    /// All 8 neighbours of the position, wrapping around the edges
+    pub fn neighbours8_wrapping(
+        &self,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&delta| self.step_wrapping(pos, delta))
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.positions().zip(&mut self.cells)
+    }
+
+    /// Cells row by row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // chunks of an empty slice panic on a zero width
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Position of the first cell matching the predicate, row by row
+    pub fn find(&self, mut f: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, cell)| f(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Grid with the rows as columns
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is out of the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is out of the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Rows on their own lines, with a line break after the last one
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows().filter(|row| !row.is_empty()) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#..\n.#.\n..#\n##.";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(MAP, |c| c)
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[(1, 1)], '#');
+        assert_eq!(grid[(2, 1)], '.');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 4)), None);
+        assert_eq!(grid.to_string(), format!("{}\n", MAP));
+        let empty = Grid::parse("", |c| c);
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.to_string(), "");
+        assert_eq!(empty.get_wrapping((0, 0)), None);
+        assert_eq!(empty.step_wrapping((0, 0), (1, 0)), None);
+        assert_eq!(empty.neighbours8_wrapping((0, 0)).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 differs in length")]
+    fn test_parse_ragged() {
+        Grid::parse("..\n...", |c| c);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        let mut corner = grid.neighbours4((0, 0)).collect::<Vec<_>>();
+        corner.sort_unstable();
+        assert_eq!(corner, [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8((2, 3)).count(), 3);
+        let mut wrapped = grid.neighbours4_wrapping((0, 0)).collect::<Vec<_>>();
+        wrapped.sort_unstable();
+        assert_eq!(wrapped, [(0, 1), (0, 3), (1, 0), (2, 0)]);
+        assert_eq!(grid.neighbours8_wrapping((0, 0)).count(), 8);
+        assert_eq!(grid.step((2, 0), (1, 0)), None);
+        assert_eq!(grid.step_wrapping((2, 0), (1, -1)), Some((0, 3)));
+        assert_eq!(grid.get_wrapping((-1, -1)), Some(&'.'));
+        assert_eq!(grid.get_wrapping((4, 5)), Some(&'#'));
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = grid();
+        assert_eq!(grid.row(3), ['#', '#', '.']);
+        assert_eq!(grid.rows().count(), 4);
+        assert_eq!(grid.column(1).collect::<String>(), ".#.#");
+        assert_eq!(grid.column(2).rev().collect::<String>(), ".#..");
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["#..#", ".#.#", "..#."]
+        );
+        assert_eq!(grid.find(|&c| c == '#'), Some((0, 0)));
+        assert_eq!(
+            grid.iter().filter(|(_, &c)| c == '#').count(),
+            grid.cells().iter().filter(|&&c| c == '#').count()
+        );
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = grid();
+        let transposed = grid.transpose();
+        assert_eq!((transposed.width(), transposed.height()), (4, 3));
+        assert_eq!(transposed.to_string(), "#..#\n.#.#\n..#.\n");
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = grid().map(|&c| c == '#');
+        assert!(grid[(0, 0)]);
+        assert_eq!(grid.cells().iter().filter(|&&b| b).count(), 5);
+        let mut grid = Grid::filled(2, 2, 0);
+        grid[(1, 0)] = 3;
+        *grid.get_mut((0, 1)).unwrap() += 2;
+        assert_eq!(grid.cells(), [0, 3, 2, 0]);
+        assert_eq!(Grid::from_fn(3, 1, |(x, _)| x).cells(), [0, 1, 2]);
+    }
+}
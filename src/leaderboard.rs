@@ -0,0 +1,442 @@
+//! Private leaderboards, as served by `/{year}/leaderboard/private/view/{id}.json`.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::{
+    client::{self, AOC_URL},
+    output::Format,
+    util::Error,
+};
+
+/// The site asks not to request a private leaderboard more often than every 15 minutes
+const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub stars: u32,
+    pub local_score: u32,
+    pub last_star: Option<DateTime<Utc>>,
+    /// Completion times of both parts of every started day
+    pub days: BTreeMap<u32, [Option<DateTime<Utc>>; 2]>,
+}
+
+impl Member {
+    /// Time between the completion of both parts of the day
+    pub fn part2_delta(&self, day: u32) -> Option<chrono::TimeDelta> {
+        match self.days.get(&day)? {
+            [Some(part1), Some(part2)] => Some(*part2 - *part1),
+            _ => None,
+        }
+    }
+}
+
+/// Members are ranked by local score, ties going to whoever got their last star first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: i32,
+    pub id: u64,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str, id: u64) -> Result<Self, Error> {
+        let invalid = |message: &str| Error::InvalidResponse(format!("leaderboard {}", message));
+        let json = serde_json::from_str::<Value>(json)
+            .map_err(|e| Error::InvalidResponse(format!("leaderboard: {}", e)))?;
+        let year = integer(&json["event"]).ok_or_else(|| invalid("has no event"))? as i32;
+        let members = json["members"]
+            .as_object()
+            .ok_or_else(|| invalid("has no members"))?
+            .values()
+            .map(|member| parse_member(member).ok_or_else(|| invalid("has an invalid member")))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut leaderboard = Leaderboard { year, id, members };
+        leaderboard.members.sort_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                member.last_star.is_none(),
+                member.last_star,
+                member.id,
+            )
+        });
+        Ok(leaderboard)
+    }
+
+    /// Days on which any member got a star
+    fn started_days(&self) -> Vec<u32> {
+        let mut days = self
+            .members
+            .iter()
+            .flat_map(|member| member.days.keys().copied())
+            .collect::<Vec<_>>();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+}
+
+fn parse_member(member: &Value) -> Option<Member> {
+    let id = integer(&member["id"])? as u64;
+    let name = match member["name"].as_str() {
+        Some(name) => name.to_string(),
+        None => format!("(anonymous user #{})", id),
+    };
+    let days = member["completion_day_level"]
+        .as_object()?
+        .iter()
+        .map(|(day, parts)| {
+            let [part1, part2] =
+                ["1", "2"].map(|part| integer(&parts[part]["get_star_ts"]).and_then(timestamp));
+            Some((day.parse().ok()?, [part1, part2]))
+        })
+        .collect::<Option<_>>()?;
+    Some(Member {
+        id,
+        name,
+        stars: integer(&member["stars"])? as u32,
+        local_score: integer(&member["local_score"])? as u32,
+        last_star: integer(&member["last_star_ts"]).and_then(timestamp),
+        days,
+    })
+}
+
+/// Numbers of older events are given as strings
+fn integer(value: &Value) -> Option<i64> {
+    value
+        .as_i64()
+        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+}
+
+/// A timestamp of 0 stands for no star at all
+fn timestamp(seconds: i64) -> Option<DateTime<Utc>> {
+    (seconds > 0)
+        .then(|| DateTime::from_timestamp(seconds, 0))
+        .flatten()
+}
+
+/// Returns the leaderboard JSON, from the cached one at `path` unless it is older than the
+/// refresh interval
+fn fetch(base_url: &str, session: &str, path: &Path, year: i32, id: u64) -> Result<String, Error> {
+    let age = fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    if age.is_some_and(|age| age < REFRESH_INTERVAL) {
+        return crate::util::read_file(path);
+    }
+    let url = format!("{}/{}/leaderboard/private/view/{}.json", base_url, year, id);
+    let json = client::get(&url, session)?;
+    Leaderboard::parse(&json, id)?;
+    crate::util::write_to_file(json.as_bytes(), path)?;
+    Ok(json)
+}
+
+/// Completion time in US Eastern time, like the site shows it
+fn format_time(time: Option<DateTime<Utc>>) -> String {
+    match time {
+        Some(time) => time
+            .with_timezone(&crate::unlock::eastern())
+            .format("%b %d %H:%M:%S")
+            .to_string(),
+        None => "-".to_string(),
+    }
+}
+
+/// Ranking of the members with their stars of every day (`*` both parts, `+` the first one),
+/// followed by the completion times of every started day.
+fn render_text(leaderboard: &Leaderboard) -> String {
+    let width = leaderboard
+        .members
+        .iter()
+        .map(|member| member.name.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(4);
+    let event_days = year_lib::event_days(leaderboard.year);
+    let mut text = format!(
+        "{} private leaderboard {}, {} members\n\n",
+        leaderboard.year,
+        leaderboard.id,
+        leaderboard.members.len()
+    );
+    text += &format!(
+        "     {:<width$}  Score  Stars  Days\n",
+        "Name",
+        width = width
+    );
+    for (rank, member) in leaderboard.members.iter().enumerate() {
+        let days = (1..=event_days)
+            .map(|day| match member.days.get(&day) {
+                Some([_, Some(_)]) => '*',
+                Some([Some(_), None]) => '+',
+                _ => '.',
+            })
+            .collect::<String>();
+        text += &format!(
+            "{:>3}) {:<width$}  {:>5}  {:>5}  {}\n",
+            rank + 1,
+            member.name,
+            member.local_score,
+            member.stars,
+            days,
+            width = width
+        );
+    }
+    for day in leaderboard.started_days() {
+        text += &format!(
+            "\nDay {}\n     {:<width$}  {:<15}  {:<15}  Delta\n",
+            day,
+            "Name",
+            "Part 1",
+            "Part 2",
+            width = width
+        );
+        let mut members = leaderboard
+            .members
+            .iter()
+            .filter_map(|member| Some((member, member.days.get(&day)?)))
+            .collect::<Vec<_>>();
+        members.sort_by_key(|(_, [part1, part2])| (part2.is_none(), *part2, *part1));
+        for (rank, (member, [part1, part2])) in members.into_iter().enumerate() {
+            let delta = member
+                .part2_delta(day)
+                .map(|delta| format!("+{}", crate::unlock::format_duration(delta)))
+                .unwrap_or_default();
+            let line = format!(
+                "{:>3}) {:<width$}  {:<15}  {:<15}  {}",
+                rank + 1,
+                member.name,
+                format_time(*part1),
+                format_time(*part2),
+                delta,
+                width = width
+            );
+            text += line.trim_end();
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// Timestamps are RFC 3339, deltas in seconds
+fn render_json(leaderboard: &Leaderboard) -> String {
+    let members = leaderboard
+        .members
+        .iter()
+        .map(|member| {
+            let days = member
+                .days
+                .iter()
+                .map(|(day, [part1, part2])| {
+                    serde_json::json!({
+                        "day": day,
+                        "part1": part1.map(|time| time.to_rfc3339()),
+                        "part2": part2.map(|time| time.to_rfc3339()),
+                        "part2_delta_s": member.part2_delta(*day).map(|delta| delta.num_seconds()),
+                    })
+                })
+                .collect::<Vec<_>>();
+            serde_json::json!({
+                "id": member.id,
+                "name": member.name,
+                "stars": member.stars,
+                "local_score": member.local_score,
+                "last_star": member.last_star.map(|time| time.to_rfc3339()),
+                "days": days,
+            })
+        })
+        .collect::<Vec<_>>();
+    let leaderboard = serde_json::json!({
+        "year": leaderboard.year,
+        "id": leaderboard.id,
+        "members": members,
+    });
+    serde_json::to_string_pretty(&leaderboard).unwrap()
+}
+
+/// Prints the private leaderboard of the year, cached next to the inputs of the account. Returns
+/// false if it could not be fetched.
+pub fn run(year: i32, id: u64, format: Format) -> bool {
+    let fetched = crate::util::get_session().and_then(|session| {
+        let dir = crate::util::account_dir(&session)?;
+        let path = dir.join(format!("{}_leaderboard_{}.json", year, id));
+        let json = fetch(AOC_URL, &session, &path, year, id)?;
+        Leaderboard::parse(&json, id)
+    });
+    let leaderboard = match fetched {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("error: leaderboard {}: {}", id, e);
+            return false;
+        }
+    };
+    match format {
+        Format::Text => print!("{}", render_text(&leaderboard)),
+        Format::Json => println!("{}", render_json(&leaderboard)),
+        Format::Csv | Format::Markdown => {
+            eprintln!("error: the leaderboard is printed as text or json only");
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::test_server::serve_once;
+
+    const LEADERBOARD: &str = include_str!("../fixtures/leaderboard.json");
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD, 1001).unwrap();
+        assert_eq!(leaderboard.year, 2021);
+        let names = leaderboard
+            .members
+            .iter()
+            .map(|member| member.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["alice", "bob", "(anonymous user #1003)", "carol"]);
+        let alice = &leaderboard.members[0];
+        assert_eq!((alice.stars, alice.local_score), (4, 11));
+        assert_eq!(
+            alice.days[&1],
+            [
+                Some(utc("2021-12-01T05:04:12Z")),
+                Some(utc("2021-12-01T05:06:13Z"))
+            ]
+        );
+        assert_eq!(alice.part2_delta(1).unwrap().num_seconds(), 121);
+        assert_eq!(leaderboard.members[1].days[&2][1], None);
+        assert_eq!(leaderboard.members[1].part2_delta(2), None);
+        assert_eq!(leaderboard.members[3].last_star, None);
+        assert_eq!(leaderboard.started_days(), [1, 2]);
+    }
+
+    #[test]
+    fn test_parse_string_numbers() {
+        let json = r#"{"event":"2016","members":{"7":{"id":"7","name":"dave","stars":"1",
+            "local_score":"1","last_star_ts":"1480586400","completion_day_level":
+            {"1":{"1":{"get_star_ts":"1480586400"}}}}}}"#;
+        let leaderboard = Leaderboard::parse(json, 7).unwrap();
+        assert_eq!(leaderboard.members[0].id, 7);
+        assert_eq!(
+            leaderboard.members[0].days[&1],
+            [Some(utc("2016-12-01T10:00:00Z")), None]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            Leaderboard::parse("<html></html>", 1),
+            Err(Error::InvalidResponse(_))
+        ));
+        assert!(matches!(
+            Leaderboard::parse(r#"{"event":"2021"}"#, 1),
+            Err(Error::InvalidResponse(_))
+        ));
+    }
+
+    #[test]
+    fn test_render_text() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD, 1001).unwrap();
+        let text = render_text(&leaderboard);
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "2021 private leaderboard 1001, 4 members");
+        assert_eq!(
+            lines[3],
+            "  1) alice                      11      4  **......................."
+        );
+        assert_eq!(
+            lines[5],
+            "  3) (anonymous user #1003)      1      1  +........................"
+        );
+        let day1 = lines.iter().position(|line| *line == "Day 1").unwrap();
+        assert_eq!(
+            lines[day1 + 2],
+            "  1) alice                   Dec 01 00:04:12  Dec 01 00:06:13  +2m 1s"
+        );
+        assert_eq!(
+            lines[day1 + 4],
+            "  3) (anonymous user #1003)  Dec 01 18:06:40  -"
+        );
+        let day2 = lines.iter().position(|line| *line == "Day 2").unwrap();
+        assert_eq!(
+            lines[day2 + 3],
+            "  2) bob                     Dec 02 00:03:20  -"
+        );
+    }
+
+    #[test]
+    fn test_render_json() {
+        let leaderboard = Leaderboard::parse(LEADERBOARD, 1001).unwrap();
+        let json = serde_json::from_str::<Value>(&render_json(&leaderboard)).unwrap();
+        assert_eq!(json["year"], 2021);
+        assert_eq!(json["members"][0]["name"], "alice");
+        assert_eq!(
+            json["members"][0]["days"][0]["part1"],
+            "2021-12-01T05:04:12+00:00"
+        );
+        assert_eq!(json["members"][0]["days"][0]["part2_delta_s"], 121);
+        assert_eq!(json["members"][1]["days"][1]["part2"], Value::Null);
+        assert_eq!(json["members"][3]["days"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_fetch_caches() {
+        let dir = temp_dir("leaderboard");
+        let path = dir.join("2021_leaderboard_1001.json");
+        let (base_url, server) = serve_once(200, LEADERBOARD);
+        assert_eq!(
+            fetch(&base_url, "abc", &path, 2021, 1001).unwrap(),
+            LEADERBOARD
+        );
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2021/leaderboard/private/view/1001.json "));
+        assert!(path.exists());
+
+        // cached for 15 minutes, the server is not asked again
+        assert_eq!(
+            fetch("http://127.0.0.1:9", "abc", &path, 2021, 1001).unwrap(),
+            LEADERBOARD
+        );
+
+        let stale = SystemTime::now() - REFRESH_INTERVAL;
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(stale)
+            .unwrap();
+        let (base_url, server) = serve_once(200, r#"{"event":"2021","members":{}}"#);
+        let json = fetch(&base_url, "abc", &path, 2021, 1001).unwrap();
+        server.join().unwrap();
+        assert_eq!(json, r#"{"event":"2021","members":{}}"#);
+        assert_eq!(fs::read_to_string(&path).unwrap(), json);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
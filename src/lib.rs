@@ -0,0 +1,55 @@
+//! Solutions of every year, and what the `adventofcode` runner is built from.
+//!
+//! [`solve`] and [`solve_day`] run a day against any input, [`registry`] gives access to every
+//! year with the timings of each phase. The command line lives in the binary.
+//!
+//! ```no_run
+//! let input = std::fs::read_to_string("inputs/2021_1.in").unwrap();
+//! let answer = adventofcode::solve(2021, 1, 1, &input)?;
+//! # Ok::<(), adventofcode::util::Error>(())
+//! ```
+
+use year_lib::Timer;
+pub use year_lib::{Answer, Solution};
+
+year_lib::aoc_years!();
+
+#[doc(hidden)]
+pub mod client;
+pub mod grid;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod search;
+#[cfg(test)]
+mod test_server;
+#[doc(hidden)]
+pub mod unlock;
+pub mod util;
+
+/// Solves both parts of the day against the given input, `None` if the day has no solution.
+///
+/// The input is used as is but for its trailing whitespace, like a puzzle input read from a file.
+/// Fails if the day reads an input it cannot get, or cannot parse the input.
+pub fn solve_day(year: i32, day: u32, input: &str) -> Result<Option<Solution>, util::Error> {
+    let registry = registry();
+    let Some(aoc_year) = registry.get(year) else {
+        return Ok(None);
+    };
+    util::with_input(input.trim_end(), || {
+        aoc_year.solve_day(day, &mut Timer::default()).transpose()
+    })
+}
+
+/// Answer of a part of the day against the given input, `None` if the day has no solution.
+/// Both parts are solved, see [`solve_day`].
+pub fn solve(year: i32, day: u32, part: u8, input: &str) -> Result<Option<Answer>, util::Error> {
+    let Some(solution) = solve_day(year, day, input)? else {
+        return Ok(None);
+    };
+    Ok(match part {
+        1 => Some(solution.part1),
+        2 => Some(solution.part2),
+        _ => None,
+    })
+}
@@ -1,14 +1,27 @@
-use std::time::Instant;
+use std::{cmp::Ordering, collections::BTreeMap, path::PathBuf, time::Instant};
 
-use clap::Parser;
-use year_lib::AOCYear;
+use adventofcode::{client, registry, unlock, util};
+use chrono::Utc;
+use clap::{
+    builder::NonEmptyStringValueParser, error::ErrorKind, CommandFactory, Parser, Subcommand,
+};
+use output::{Format, Output, Record, RecordStatus};
+use year_lib::{AOCYear, Answer, DayRun};
 
-mod aoc2018;
-mod aoc2020;
-mod aoc2021;
-mod aoc2022;
-mod aoc2025;
-mod util;
+mod bench;
+mod check;
+mod describe;
+mod leaderboard;
+mod output;
+mod progress;
+mod scaffold;
+mod submit;
+#[cfg(test)]
+mod test_server;
+
+/// A year of the crate, whose days fail with its errors
+type Year = dyn AOCYear<Error = util::Error>;
+type Registry = year_lib::Registry<util::Error>;
 
 #[derive(Debug, Parser)]
 #[command(name = "aoc", about = "AoC runner.")]
@@ -17,41 +30,642 @@ struct Args {
     #[arg(short, long)]
     all: bool,
 
+    /// Run all solutions of every year
+    #[arg(long, conflicts_with_all = ["year", "day", "all", "bench"])]
+    all_years: bool,
+
+    /// Run the days on N threads, 0 for one per core
+    #[arg(short, long, value_name = "N", default_value_t = 1)]
+    jobs: usize,
+
     /// Which year to run (default: last available aoc year)
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     year: Option<i32>,
 
     /// Which day to run (default: last available aoc day)
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     day: Option<u32>,
+
+    /// Verify the answers against the known answers in answers/{year}.toml
+    #[arg(short, long)]
+    check: bool,
+
+    /// Run each day N times and report min/median/max of every phase
+    #[arg(short, long, value_name = "N")]
+    bench: Option<usize>,
+
+    /// Name the benchmark in the history, e.g. after the implementation being benchmarked
+    #[arg(long, value_name = "NAME", requires = "bench")]
+    label: Option<String>,
+
+    /// Compare the benchmark with the history of a commit or label, failing if a phase got slower
+    #[arg(
+        long,
+        value_name = "BASELINE",
+        requires = "bench",
+        value_parser = NonEmptyStringValueParser::new()
+    )]
+    compare: Option<String>,
+
+    /// Slowdown over the baseline to flag, in percent
+    #[arg(long, value_name = "PCT", default_value_t = 10.0, requires = "compare")]
+    threshold: f64,
+
+    /// Where inputs are cached (default: AOC_CACHE_DIR, or else ~/.cache/adventofcode)
+    #[arg(long, value_name = "DIR", global = true)]
+    cache_dir: Option<PathBuf>,
+
+    /// Run the day against the given file instead of its puzzle input, - for stdin
+    #[arg(short, long, value_name = "FILE", conflicts_with_all = ["all", "all_years"])]
+    input: Option<PathBuf>,
+
+    /// Run the day against its example inputs in inputs/, or only against example N
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        conflicts_with_all = ["all", "all_years", "input", "check", "bench"]
+    )]
+    example: Option<Option<u32>>,
+
+    /// Print the answers as text, or as records of every part for dashboards and tables
+    #[arg(short, long, value_enum, default_value_t = Format::Text, conflicts_with = "bench")]
+    format: Format,
+
+    /// Run an alternative implementation of the day, see --list for the variants of each day
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["all", "all_years"])]
+    variant: Option<String>,
+
+    /// Wait until the puzzle unlocks (default: the next one), then download its input and run it
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["all", "all_years", "input", "example", "bench", "list"]
+    )]
+    wait: bool,
+
+    /// List the available years and days, only of the given year with --year
+    #[arg(short, long)]
+    list: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Compute the answer of a part and submit it
+    Submit {
+        /// Which part to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Create the day from a template, and its year if missing, then download its input and example
+    New,
+    /// Print the puzzle description of the day and save its examples in inputs/
+    Describe {
+        /// Print it as markdown instead of text
+        #[arg(short, long)]
+        markdown: bool,
+        /// Fetch it again even if it is cached
+        #[arg(short, long)]
+        refresh: bool,
+    },
+    /// Print the private leaderboard of the year, as text or as JSON with --format json
+    Leaderboard {
+        /// Id of the leaderboard, as in its URL
+        id: u64,
+    },
+    /// Print a calendar of the solved parts of every year, or of the given year
+    Progress {
+        /// Also count the stars of the event calendar pages, solved without a known answer
+        #[arg(short, long)]
+        stars: bool,
+    },
+    /// Download the inputs of every unlocked day of the year that are not cached yet
+    Fetch {
+        /// Download the cached inputs again if they changed on the server
+        #[arg(short, long)]
+        refresh: bool,
+    },
 }
 
 fn main() {
     let args = Args::parse();
+    if let Err(e) = check_conflicts(&args) {
+        e.exit();
+    }
+    if let Some(dir) = &args.cache_dir {
+        util::set_cache_dir(dir.clone());
+    }
+    if let Some(path) = &args.input {
+        if let Err(e) = util::set_input_override(path) {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+    let registry = registry();
+    if args.list {
+        print_list(&registry, args.year);
+        return;
+    }
+    if args.all_years {
+        let days = registry
+            .years()
+            .flat_map(|year| {
+                registry
+                    .get(year)
+                    .unwrap()
+                    .days()
+                    .map(move |day| (year, day))
+            })
+            .collect::<Vec<_>>();
+        run_days(&registry, &days, None, args.jobs, args.check, args.format);
+        return;
+    }
+    if let Some(Command::Fetch { refresh }) = args.command {
+        let year = args.year.unwrap_or(unlock::latest_puzzle(Utc::now()).0);
+        if !fetch_inputs(year, refresh) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    if let Some(Command::Progress { stars }) = args.command {
+        let years = match args.year {
+            Some(year) => vec![year],
+            None => (progress::FIRST_YEAR..=unlock::latest_puzzle(Utc::now()).0).collect(),
+        };
+        if !progress::run(&registry, &years, stars) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    if let Some(Command::Leaderboard { id }) = args.command {
+        let year = args.year.unwrap_or(unlock::latest_puzzle(Utc::now()).0);
+        if !leaderboard::run(year, id, args.format) {
+            std::process::exit(1);
+        }
+        return;
+    }
     let (year, day) = return_year_and_day(&args);
-    let aoc_year = return_aoc_year(year);
+    if let Some(Command::New) = args.command {
+        if !scaffold::run(year, day) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    if let Some(Command::Describe { markdown, refresh }) = args.command {
+        let style = match markdown {
+            true => describe::Style::Markdown,
+            false => describe::Style::Text,
+        };
+        if !describe::run(year, day, style, refresh) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    let aoc_year = return_aoc_year(&registry, year);
+    let variant = args.variant.as_deref();
+    if let Some(variant) = variant {
+        if !aoc_year.variants(day).contains(&variant) {
+            unknown_variant(aoc_year, year, day, variant);
+        }
+    }
+    if args.wait {
+        if !registry.is_available(year, day) {
+            not_implemented(year, Some(day));
+        }
+        if let Err(e) = unlock::wait(year, day).and_then(|_| unlock::fetch_input(year, day)) {
+            eprintln!("error: {} day {}: {}", year, day, e);
+            std::process::exit(1);
+        }
+    }
+    if let Some(Command::Submit { part }) = args.command {
+        let run = run_day(aoc_year, year, day, variant);
+        let answer = match part {
+            1 => &run.solution.part1,
+            _ => &run.solution.part2,
+        };
+        if !submit::run(year, day, part, answer) {
+            std::process::exit(1);
+        }
+        return;
+    }
+    if let Some(example) = args.example {
+        run_examples(year, day, example, aoc_year, variant);
+        return;
+    }
+    if let Some(repeat) = args.bench {
+        let days = match &args.all {
+            true => aoc_year.available_days().to_vec(),
+            false if registry.is_available(year, day) => vec![day],
+            false => not_implemented(year, Some(day)),
+        };
+        let mut success = true;
+        let benches = days
+            .iter()
+            .map(|&day| {
+                let (bench, run) = bench::run(aoc_year, day, variant, repeat).unwrap_or_else(|e| {
+                    report_day_error(year, day, &e);
+                    std::process::exit(1)
+                });
+                // a faster variant is only worth it if it gives the answers of the day
+                if let Some(variant) = variant {
+                    let day_run = run_day(aoc_year, year, day, None);
+                    success &= check::report_variant(year, &day_run, variant, &run);
+                }
+                bench
+            })
+            .collect::<Vec<_>>();
+        bench::report(year, &benches, repeat);
+        // a variant names its benchmark unless labelled otherwise
+        let label = args.label.as_deref().or(variant);
+        let baseline = args.compare.as_deref();
+        success &= bench::save(
+            year,
+            &benches,
+            repeat,
+            variant,
+            label,
+            baseline,
+            args.threshold,
+        );
+        if !success {
+            std::process::exit(1);
+        }
+        return;
+    }
+    let days = match &args.all {
+        true => aoc_year.days().map(|day| (year, day)).collect::<Vec<_>>(),
+        false if registry.is_available(year, day) => vec![(year, day)],
+        false => not_implemented(year, Some(day)),
+    };
+    run_days(
+        &registry,
+        &days,
+        variant,
+        args.jobs,
+        args.check,
+        args.format,
+    );
+}
+
+/// Conflicts clap cannot express between the arguments and a subcommand: the answers computed
+/// from another input than the puzzle input must not be submitted
+fn check_conflicts(args: &Args) -> Result<(), clap::Error> {
+    let Some(Command::Submit { .. }) = args.command else {
+        return Ok(());
+    };
+    let given = [
+        (args.input.is_some(), "--input <FILE>"),
+        (args.example.is_some(), "--example [<N>]"),
+    ];
+    match given.into_iter().find(|&(given, _)| given) {
+        Some((_, arg)) => Err(Args::command().error(
+            ErrorKind::ArgumentConflict,
+            format!("the argument '{}' cannot be used with 'submit'", arg),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Runs the days on `jobs` threads, printing their answers, or checking them against the known
+/// answers, in order. Other formats than text print a record of every part instead. Checking the
+/// days also checks that their variants give the same answers. A day that fails is reported and
+/// the others go on, the run exits with an error code if any day or check fails.
+fn run_days(
+    registry: &Registry,
+    days: &[(i32, u32)],
+    variant: Option<&str>,
+    jobs: usize,
+    check: bool,
+    format: Format,
+) {
+    let jobs = match jobs {
+        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        _ => jobs,
+    };
+    let mut known_answers = BTreeMap::new();
+    let mut output = Output::new(format);
+    let mut success = true;
     let now = Instant::now();
-    match &args.all {
-        true => aoc_year.run_all(),
-        false => aoc_year.run_day(day),
+    registry.run_days(days, variant, jobs, |year, day, run| {
+        let known_answers = check.then(|| load_known_answers(&mut known_answers, year));
+        let run = match run.transpose() {
+            Ok(run) => run,
+            // a day without its input has nothing to check until its answers are known
+            Err(e @ util::Error::Input { .. })
+                if known_answers.is_some_and(|known_answers| !known_answers.has_day(day)) =>
+            {
+                eprintln!("warning: {}, not checked", e);
+                return;
+            }
+            Err(e) => {
+                report_day_error(year, day, &e);
+                success = false;
+                return;
+            }
+        };
+        match (format, &run) {
+            (Format::Text, None) => println!("{} day {}: not implemented", year, day),
+            (Format::Text, Some(run)) => match known_answers {
+                Some(known_answers) => success &= check::report(year, run, known_answers),
+                None => print_run(year, run),
+            },
+            _ => {
+                for record in Record::of_day(year, day, run.as_ref(), known_answers) {
+                    success &= record.status != RecordStatus::Checked(check::Status::Fail);
+                    output.push(record);
+                }
+            }
+        }
+        if let (true, None, Some(run)) = (check, variant, &run) {
+            success &= check_variants(registry.get(year).unwrap(), year, run, format);
+        }
+    });
+    output.finish();
+    let elapsed = now.elapsed();
+    if !check && format == Format::Text {
+        println!("time spent {} us", elapsed.as_micros());
+    }
+    if !success {
+        std::process::exit(1);
+    }
+}
+
+/// Known answers of the year, loaded once
+fn load_known_answers(
+    known_answers: &mut BTreeMap<i32, check::KnownAnswers>,
+    year: i32,
+) -> &check::KnownAnswers {
+    known_answers.entry(year).or_insert_with(|| {
+        check::KnownAnswers::load(year).unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            std::process::exit(1)
+        })
+    })
+}
+
+/// Runs every variant of the day and checks that it gives the answers of the day. The records of
+/// other formats than text are the ones of the day, a variant only reports its failure there.
+fn check_variants(aoc_year: &Year, year: i32, run: &DayRun, format: Format) -> bool {
+    aoc_year.variants(run.day).iter().all(|variant| {
+        match aoc_year.run_variant(run.day, variant).unwrap() {
+            Ok(variant_run) if format == Format::Text => {
+                check::report_variant(year, run, variant, &variant_run)
+            }
+            Ok(variant_run) => match check::variant_mismatch(run, &variant_run) {
+                Some((part, expected, answer)) => {
+                    eprintln!(
+                        "error: {} day {} variant {} part {}: expected {}, got {}",
+                        year, run.day, variant, part, expected, answer
+                    );
+                    false
+                }
+                None => true,
+            },
+            Err(e) => {
+                report_day_error(year, run.day, &e);
+                false
+            }
+        }
+    })
+}
+
+/// Caches the inputs of every unlocked day of the year, one request at a time. Returns false if
+/// any input could not be fetched.
+fn fetch_inputs(year: i32, refresh: bool) -> bool {
+    let (latest_year, latest_day) = unlock::latest_puzzle(Utc::now());
+    let last_day = match year.cmp(&latest_year) {
+        Ordering::Less => year_lib::event_days(year),
+        Ordering::Equal => latest_day,
+        Ordering::Greater => 0,
+    };
+    if last_day == 0 {
+        eprintln!("error: no puzzle of {} is unlocked yet", year);
+        return false;
+    }
+    let mut success = true;
+    for day in 1..=last_day {
+        match util::prefetch_puzzle_input(year, day, refresh) {
+            Ok(fetched) => println!("{} day {}: {}", year, day, fetched),
+            Err(e @ util::Error::MissingSession) => {
+                eprintln!("error: {}", e);
+                return false;
+            }
+            Err(e) => {
+                eprintln!("error: {} day {}: {}", year, day, e);
+                success = false;
+            }
+        }
     }
-    println!("time spent {} us", now.elapsed().as_micros());
+    success
 }
 
+fn run_examples(year: i32, day: u32, example: Option<u32>, aoc_year: &Year, variant: Option<&str>) {
+    if !aoc_year.available_days().contains(&day) {
+        not_implemented(year, Some(day));
+    }
+    let examples = util::example_inputs(year, day).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        std::process::exit(1)
+    });
+    let examples = examples
+        .into_iter()
+        .filter(|(n, _)| example.is_none_or(|example| example == *n))
+        .collect::<Vec<_>>();
+    if examples.is_empty() {
+        eprintln!("error: {} day {} has no such example input", year, day);
+        std::process::exit(1);
+    }
+    for (n, path) in examples {
+        if let Err(e) = util::set_input_override(&path) {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+        println!("example {} ({})", n, path.display());
+        print_run(year, &run_day(aoc_year, year, day, variant));
+    }
+}
+
+fn print_run(year: i32, run: &DayRun) {
+    println!("{} day {}", year, run.day);
+    for (part, answer) in run.solution.parts() {
+        match answer {
+            Answer::Unsolved => {}
+            _ if answer.is_multiline() => println!("part {}:\n{}", part, answer),
+            _ => println!("part {}: {}", part, answer),
+        }
+    }
+    let timings = &run.timings;
+    println!(
+        "input {} us, parse {} us, part 1 {} us, part 2 {} us",
+        timings.input.as_micros(),
+        timings.parse.as_micros(),
+        timings.part1.as_micros(),
+        timings.part2.as_micros()
+    );
+}
+
+/// The given year and day, by default the last unlocked puzzle, or the next one to unlock when
+/// waiting for it
 fn return_year_and_day(opt: &Args) -> (i32, u32) {
-    let (latest_year, latest_day) = util::get_latest_year_and_day();
+    let (latest_year, latest_day) = match opt.wait && opt.year.is_none() && opt.day.is_none() {
+        true => unlock::next_puzzle(Utc::now()),
+        false => unlock::latest_puzzle(Utc::now()),
+    };
     let year = opt.year.unwrap_or(latest_year);
     let day = opt.day.unwrap_or(latest_day);
     (year, day)
 }
 
-fn return_aoc_year(year: i32) -> Box<dyn AOCYear> {
-    match year {
-        2018 => aoc2018::AOC2018::new(),
-        2020 => aoc2020::AOC2020::new(),
-        2021 => aoc2021::AOC2021::new(),
-        2022 => aoc2022::AOC2022::new(),
-        2025 => aoc2025::AOC2025::new(),
-        _ => unimplemented!("year {}", year),
+fn return_aoc_year(registry: &Registry, year: i32) -> &Year {
+    registry
+        .get(year)
+        .unwrap_or_else(|| not_implemented(year, None))
+}
+
+/// Runs the day, exiting with an error if it fails
+fn run_day(aoc_year: &Year, year: i32, day: u32, variant: Option<&str>) -> DayRun {
+    match variant {
+        Some(variant) => aoc_year.run_variant(day, variant),
+        None => aoc_year.run_day(day),
+    }
+    .unwrap_or_else(|| not_implemented(year, Some(day)))
+    .unwrap_or_else(|e| {
+        report_day_error(year, day, &e);
+        std::process::exit(1)
+    })
+}
+
+/// Reports the error of a day, with the day unless the error already tells it
+fn report_day_error(year: i32, day: u32, e: &util::Error) {
+    match e {
+        util::Error::Input { .. } => eprintln!("error: {}", e),
+        _ => eprintln!("error: {} day {}: {}", year, day, e),
+    }
+}
+
+fn unknown_variant(aoc_year: &Year, year: i32, day: u32, variant: &str) -> ! {
+    eprintln!("error: {} day {} has no variant {}", year, day, variant);
+    match aoc_year.variants(day) {
+        [] => eprintln!("{} day {} has no variants", year, day),
+        variants => eprintln!("variants: {}", variants.join(", ")),
+    }
+    std::process::exit(1)
+}
+
+fn not_implemented(year: i32, day: Option<u32>) -> ! {
+    match day {
+        Some(day) => eprintln!("error: {} day {} is not implemented", year, day),
+        None => eprintln!("error: year {} is not implemented", year),
+    }
+    eprintln!("use --list to see the available years and days");
+    std::process::exit(1)
+}
+
+fn print_list(registry: &Registry, year: Option<i32>) {
+    for y in registry
+        .years()
+        .filter(|y| year.is_none_or(|year| year == *y))
+    {
+        let aoc_year = registry.get(y).unwrap();
+        let days = aoc_year.available_days();
+        println!(
+            "{}: {} ({} of {} days)",
+            y,
+            format_days(days),
+            days.len(),
+            aoc_year.day_count()
+        );
+        for &day in days {
+            if let variants @ [_, ..] = aoc_year.variants(day) {
+                println!("  day {} variants: {}", day, variants.join(", "));
+            }
+        }
+    }
+}
+
+/// Formats sorted days with runs of consecutive days as ranges, e.g. `1-11, 13`
+fn format_days(days: &[u32]) -> String {
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for &day in days {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == day => *end = day,
+            _ => ranges.push((day, day)),
+        }
+    }
+    ranges
+        .iter()
+        .map(|&(start, end)| match start == end {
+            true => start.to_string(),
+            false => format!("{}-{}", start, end),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_args() {
+        Args::command().debug_assert();
+    }
+
+    #[test]
+    fn test_empty_baseline() {
+        let args = Args::try_parse_from(["aoc", "-b", "3", "--compare", ""]);
+        assert_eq!(args.unwrap_err().kind(), ErrorKind::InvalidValue);
+        assert!(Args::try_parse_from(["aoc", "-b", "3", "--compare", "v1"]).is_ok());
+    }
+
+    #[test]
+    fn test_submit_conflicts() {
+        let check = |args: &[&str]| check_conflicts(&Args::try_parse_from(args).unwrap());
+        assert!(check(&["aoc", "-y", "2021", "-d", "1", "submit", "1"]).is_ok());
+        assert!(check(&["aoc", "-i", "-", "-d", "1"]).is_ok());
+        for args in [
+            &["aoc", "-i", "-", "submit", "1"][..],
+            &["aoc", "-e", "-d", "1", "submit", "2"],
+            &["aoc", "--example", "2", "-d", "1", "submit", "1"],
+        ] {
+            assert_eq!(check(args).unwrap_err().kind(), ErrorKind::ArgumentConflict);
+        }
+    }
+
+    #[test]
+    fn test_format_days() {
+        assert_eq!(format_days(&[1, 2, 3, 5, 7, 8]), "1-3, 5, 7-8");
+        assert_eq!(format_days(&[4]), "4");
+        assert_eq!(format_days(&[]), "");
+    }
+
+    #[test]
+    fn test_registry() {
+        let registry = registry();
+        assert!(registry.years().any(|year| year == 2021));
+        assert!(registry.is_available(2021, 1));
+        assert!(!registry.is_available(2021, 26));
+        assert!(!registry.is_available(2019, 1));
+        assert!(registry.available().contains(&(2022, 1)));
+        assert_eq!(registry.get(2021).unwrap().day_count(), 25);
+        assert_eq!(registry.get(2025).unwrap().day_count(), 12);
+        assert_eq!(registry.get(2020).unwrap().variants(15), ["map"]);
+        assert_eq!(registry.get(2018).unwrap().variants(11), ["naive"]);
+        assert!(registry.get(2020).unwrap().variants(1).is_empty());
+    }
+
+    #[test]
+    fn test_run_days_in_order() {
+        let registry = registry();
+        let days = [(2021, 2), (2025, 1), (2022, 13), (2021, 1), (2019, 1)];
+        let mut handed = Vec::new();
+        registry.run_days(&days, None, 3, |year, day, run| {
+            assert_eq!(run.is_some(), registry.is_available(year, day));
+            handed.push((year, day));
+        });
+        assert_eq!(handed, days);
     }
 }
@@ -0,0 +1,136 @@
+//! Recognition of the letters some puzzles draw on a grid instead of giving their answer.
+//!
+//! Two fonts are used across the events: letters 4 wide (`Y` 5) and 6 high one column apart, and
+//! letters 6 wide and 10 high two columns apart.
+
+use lazy_static::lazy_static;
+
+use crate::grid::Grid;
+
+/// Letters of the 6 high font, drawn in a row
+const SMALL_FONT: (&str, &[&str]) = (
+    "ABCEFGHIJKLOPRSUYZ",
+    &[
+        ".##..###...##..####.####..##..#..#.###...##.#..#.#.....##..###..###...###.#..#.#...#.####",
+        "#..#.#..#.#..#.#....#....#..#.#..#..#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#....#",
+        "#..#.###..#....###..###..#....####..#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#....#.",
+        "####.#..#.#....#....#....#.##.#..#..#.....#.#.#..#....#..#.###..###...##..#..#...#....#..",
+        "#..#.#..#.#..#.#....#....#..#.#..#..#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#...#...",
+        "#..#.###...##..####.#.....###.#..#.###..##..#..#.####..##..#....#..#.###...##....#...####",
+    ],
+);
+
+/// Letters of the 10 high font, drawn in a row
+const LARGE_FONT: (&str, &[&str]) = (
+    "ABCEFGHJKLNPRXZ",
+    &[
+        "..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######",
+        ".#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#",
+        "#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#",
+        "#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#.",
+        "#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#..",
+        "######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#...",
+        "#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#....",
+        "#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.....",
+        "#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.....",
+        "#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######",
+    ],
+);
+
+lazy_static! {
+    static ref GLYPHS: Vec<(Grid<bool>, char)> = [SMALL_FONT, LARGE_FONT]
+        .iter()
+        .flat_map(|&(letters, rows)| {
+            let font = Grid::parse(&rows.join("\n"), |c| c == '#');
+            glyphs(&font).into_iter().zip(letters.chars())
+        })
+        .collect();
+}
+
+/// Splits the image into its glyphs at the blank columns between them, leaving out the blank rows
+/// above and below
+fn glyphs(image: &Grid<bool>) -> Vec<Grid<bool>> {
+    let mut lit_rows = (0..image.height()).filter(|&y| image.row(y).contains(&true));
+    let Some(top) = lit_rows.next() else {
+        return Vec::new();
+    };
+    let height = lit_rows.next_back().unwrap_or(top) - top + 1;
+    let mut glyphs = Vec::new();
+    let mut start = None;
+    for x in 0..=image.width() {
+        let blank = x == image.width() || (top..top + height).all(|y| !image[(x, y)]);
+        match (start, blank) {
+            (None, false) => start = Some(x),
+            (Some(from), true) => {
+                glyphs.push(Grid::from_fn(x - from, height, |(dx, dy)| {
+                    image[(from + dx, top + dy)]
+                }));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    glyphs
+}
+
+/// Letters drawn by the lit cells of the image, if every glyph is a letter of one of the fonts
+pub fn recognise(image: &Grid<bool>) -> Option<String> {
+    let glyphs = glyphs(image);
+    if glyphs.is_empty() {
+        return None;
+    }
+    glyphs
+        .iter()
+        .map(|glyph| {
+            GLYPHS
+                .iter()
+                .find(|(letter, _)| letter == glyph)
+                .map(|&(_, c)| c)
+        })
+        .collect()
+}
+
+/// The image with `#` for the lit cells and `.` for the others, without a trailing line break
+pub fn draw(image: &Grid<bool>) -> String {
+    let drawing = image.map(|&lit| if lit { '#' } else { '.' }).to_string();
+    drawing.trim_end().to_string()
+}
+
+/// The letters of the image, or the image itself when it does not spell anything known
+pub fn read(image: &Grid<bool>) -> String {
+    recognise(image).unwrap_or_else(|| draw(image))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(rows: &[&str]) -> Grid<bool> {
+        Grid::parse(&rows.join("\n"), |c| c == '#')
+    }
+
+    #[test]
+    fn test_fonts() {
+        for (letters, rows) in [SMALL_FONT, LARGE_FONT] {
+            assert_eq!(recognise(&image(rows)).as_deref(), Some(letters));
+        }
+    }
+
+    #[test]
+    fn test_recognise() {
+        let small = image(&[
+            "........................................",
+            "####.#..#.###..###..####.####..##..#....",
+            "...#.#..#.#..#.#..#.#....#....#..#.#....",
+            "..#..#..#.#..#.#..#.###..###..#....#....",
+            ".#...#..#.###..###..#....#....#....#....",
+            "#....#..#.#....#.#..#....#....#..#.#....",
+            "####..##..#....#..#.#....####..##..####.",
+        ]);
+        assert_eq!(recognise(&small).as_deref(), Some("ZUPRFECL"));
+        let unknown = image(&["#...#", ".#.#.", "..#..", ".#.#.", "#...#", "....."]);
+        assert_eq!(recognise(&unknown), None);
+        assert_eq!(read(&unknown), "#...#\n.#.#.\n..#..\n.#.#.\n#...#\n.....");
+        assert_eq!(recognise(&image(&["...", "..."])), None);
+    }
+}
@@ -0,0 +1,241 @@
+use std::{fmt, time::Duration};
+
+use clap::ValueEnum;
+use year_lib::{Answer, DayRun};
+
+use crate::check::{self, KnownAnswers, Status};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordStatus {
+    /// The part has an answer, not checked
+    Solved,
+    Checked(Status),
+    /// The part has no solution
+    Unsolved,
+    /// The day has no solution
+    NotImplemented,
+}
+
+impl fmt::Display for RecordStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordStatus::Solved => write!(f, "solved"),
+            RecordStatus::Checked(Status::Pass) => write!(f, "pass"),
+            RecordStatus::Checked(Status::Fail) => write!(f, "fail"),
+            RecordStatus::Checked(Status::Unknown) => write!(f, "unknown"),
+            RecordStatus::Unsolved => write!(f, "unsolved"),
+            RecordStatus::NotImplemented => write!(f, "not_implemented"),
+        }
+    }
+}
+
+/// Outcome of a single part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: i32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Time spent solving the part
+    pub duration: Duration,
+    pub status: RecordStatus,
+}
+
+impl Record {
+    /// Records both parts of a day, checked against the known answers if given
+    pub fn of_day(
+        year: i32,
+        day: u32,
+        run: Option<&DayRun>,
+        known_answers: Option<&KnownAnswers>,
+    ) -> [Record; 2] {
+        [1, 2].map(|part| {
+            let Some(run) = run else {
+                return Record {
+                    year,
+                    day,
+                    part,
+                    answer: None,
+                    duration: Duration::ZERO,
+                    status: RecordStatus::NotImplemented,
+                };
+            };
+            let (answer, duration) = match part {
+                1 => (&run.solution.part1, run.timings.part1),
+                _ => (&run.solution.part2, run.timings.part2),
+            };
+            let known = known_answers.and_then(|known_answers| known_answers.get(day, part));
+            let status = match (answer, known_answers) {
+                (Answer::Unsolved, _) if known.is_none() => RecordStatus::Unsolved,
+                (_, Some(_)) => RecordStatus::Checked(check::status(answer, known)),
+                (_, None) => RecordStatus::Solved,
+            };
+            Record {
+                year,
+                day,
+                part,
+                answer: (*answer != Answer::Unsolved).then(|| answer.to_string()),
+                duration,
+                status,
+            }
+        })
+    }
+}
+
+/// Prints the records as they come in the given format. JSON is printed at once when finishing.
+#[derive(Debug)]
+pub struct Output {
+    format: Format,
+    records: Vec<Record>,
+}
+
+impl Output {
+    /// Starts the output, printing the header of the format
+    pub fn new(format: Format) -> Self {
+        match format {
+            Format::Csv => println!("year,day,part,answer,duration_us,status"),
+            Format::Markdown => {
+                println!("| Year | Day | Part | Answer | Time (us) | Status |");
+                println!("| ---: | ---: | ---: | --- | ---: | --- |");
+            }
+            Format::Text | Format::Json => {}
+        }
+        Self {
+            format,
+            records: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, record: Record) {
+        match self.format {
+            Format::Csv => println!("{}", csv_row(&record)),
+            Format::Markdown => println!("{}", markdown_row(&record)),
+            Format::Text | Format::Json => self.records.push(record),
+        }
+    }
+
+    pub fn finish(self) {
+        if self.format == Format::Json {
+            println!("{}", json(&self.records));
+        }
+    }
+}
+
+fn csv_row(record: &Record) -> String {
+    let answer = record.answer.as_deref().unwrap_or_default();
+    let answer = match answer.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", answer.replace('"', "\"\"")),
+        false => answer.to_string(),
+    };
+    format!(
+        "{},{},{},{},{},{}",
+        record.year,
+        record.day,
+        record.part,
+        answer,
+        record.duration.as_micros(),
+        record.status
+    )
+}
+
+fn markdown_row(record: &Record) -> String {
+    let answer = match &record.answer {
+        Some(answer) if answer.contains('\n') => answer
+            .lines()
+            .map(|line| format!("`{}`", line))
+            .collect::<Vec<_>>()
+            .join("<br>"),
+        Some(answer) => answer.replace('|', "\\|"),
+        None => String::new(),
+    };
+    format!(
+        "| {} | {} | {} | {} | {} | {} |",
+        record.year,
+        record.day,
+        record.part,
+        answer,
+        record.duration.as_micros(),
+        record.status
+    )
+}
+
+/// Answers are strings, numbers may not fit in a double
+fn json(records: &[Record]) -> String {
+    let records = records
+        .iter()
+        .map(|record| {
+            serde_json::json!({
+                "year": record.year,
+                "day": record.day,
+                "part": record.part,
+                "answer": record.answer,
+                "duration_us": record.duration.as_micros() as u64,
+                "status": record.status.to_string(),
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&records).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use year_lib::{Solution, Timings};
+
+    use super::*;
+
+    fn records(known_answers: Option<&KnownAnswers>) -> Vec<Record> {
+        let run = DayRun {
+            day: 10,
+            solution: Solution::new(13140u32, "#.\n.#"),
+            timings: Timings {
+                part1: Duration::from_micros(12),
+                part2: Duration::from_micros(34),
+                ..Timings::default()
+            },
+        };
+        let mut records = Record::of_day(2022, 10, Some(&run), known_answers).to_vec();
+        records.extend(Record::of_day(2022, 11, None, known_answers));
+        records
+    }
+
+    #[test]
+    fn test_records() {
+        let records = records(None);
+        assert_eq!(records[0].answer.as_deref(), Some("13140"));
+        assert_eq!(records[0].duration, Duration::from_micros(12));
+        assert_eq!(records[0].status, RecordStatus::Solved);
+        assert_eq!(records[2].answer, None);
+        assert_eq!(records[3].status, RecordStatus::NotImplemented);
+
+        let known_answers = KnownAnswers::parse("[day10]\npart1 = 13141\n").unwrap();
+        let records = self::records(Some(&known_answers));
+        assert_eq!(records[0].status, RecordStatus::Checked(Status::Fail));
+        assert_eq!(records[1].status, RecordStatus::Checked(Status::Unknown));
+    }
+
+    #[test]
+    fn test_formats() {
+        let records = records(None);
+        assert_eq!(csv_row(&records[0]), "2022,10,1,13140,12,solved");
+        assert_eq!(csv_row(&records[1]), "2022,10,2,\"#.\n.#\",34,solved");
+        assert_eq!(csv_row(&records[2]), "2022,11,1,,0,not_implemented");
+        assert_eq!(
+            markdown_row(&records[1]),
+            "| 2022 | 10 | 2 | `#.`<br>`.#` | 34 | solved |"
+        );
+        let json = serde_json::from_str::<serde_json::Value>(&json(&records)).unwrap();
+        assert_eq!(json[0]["answer"], "13140");
+        assert_eq!(json[0]["duration_us"], 12);
+        assert_eq!(json[2]["answer"], serde_json::Value::Null);
+        assert_eq!(json[3]["status"], "not_implemented");
+    }
+}
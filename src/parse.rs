@@ -0,0 +1,415 @@
+//! Parsing of puzzle inputs that tells where a malformed or truncated input breaks.
+//!
+//! A [`Span`] is a piece of the input that knows the line and column it starts at, every piece
+//! split from it too, so the errors of the parsers built on it point at the place they failed:
+//!
+//! ```
+//! use adventofcode::parse::{ParseError, Span};
+//!
+//! let input = Span::new("position=<9, 1> velocity=<0, 2>\nposition=<7, x> velocity=<-1, 0>");
+//! let points = input
+//!     .lines()
+//!     .map(|line| {
+//!         let [x, y, _, _] = line.scan("position=<{}, {}> velocity=<{}, {}>")?;
+//!         Ok((x.parse::<i32>()?, y.parse::<i32>()?))
+//!     })
+//!     .collect::<Result<Vec<_>, ParseError>>();
+//! let error = points.unwrap_err();
+//! assert_eq!((error.line, error.column), (2, 14));
+//! assert_eq!(error.message, "cannot parse `x`: invalid digit found in string");
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use crate::grid::Grid;
+
+/// Where and why an input could not be parsed, lines and columns starting at 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Span<'a> {
+    /// The whole input, starting at line 1, column 1
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            text: input,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn as_str(self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(self) -> usize {
+        self.line
+    }
+
+    pub fn column(self) -> usize {
+        self.column
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// An error at the start of the span
+    pub fn error(self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            message: message.into(),
+        }
+    }
+
+    /// The empty span right after this one, where a truncated input stops
+    pub fn end(self) -> Self {
+        self.slice(self.text.len(), self.text.len())
+    }
+
+    /// The bytes `start..end` of the span, with their own position
+    fn slice(self, start: usize, end: usize) -> Self {
+        let before = &self.text[..start];
+        let (line, column) = match before.rfind('\n') {
+            Some(last) => (
+                self.line + before.matches('\n').count(),
+                before[last + 1..].chars().count() + 1,
+            ),
+            None => (self.line, self.column + before.chars().count()),
+        };
+        Self {
+            text: &self.text[start..end],
+            line,
+            column,
+        }
+    }
+
+    /// The part of the span that `piece` borrows
+    fn sub(self, piece: &'a str) -> Self {
+        let start = piece.as_ptr() as usize - self.text.as_ptr() as usize;
+        self.slice(start, start + piece.len())
+    }
+
+    /// The parts of the span that the pieces borrow, in order. Each one is positioned from the
+    /// previous one, so the span is scanned once rather than once per piece.
+    fn subs(self, pieces: impl Iterator<Item = &'a str>) -> impl Iterator<Item = Self> {
+        let mut rest = self;
+        pieces.map(move |piece| {
+            let start = piece.as_ptr() as usize - rest.text.as_ptr() as usize;
+            rest = rest.slice(start, rest.text.len());
+            Self {
+                text: &rest.text[..piece.len()],
+                ..rest
+            }
+        })
+    }
+
+    pub fn trim(self) -> Self {
+        self.sub(self.text.trim())
+    }
+
+    /// Lines of the span, without their line breaks
+    pub fn lines(self) -> impl Iterator<Item = Self> {
+        self.subs(self.text.lines())
+    }
+
+    /// Groups of lines separated by blank lines
+    pub fn paragraphs(self) -> impl Iterator<Item = Self> {
+        let mut lines = self.lines().peekable();
+        std::iter::from_fn(move || {
+            while lines.next_if(|line| line.text.trim().is_empty()).is_some() {}
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+                last = line;
+            }
+            let start = first.text.as_ptr() as usize - self.text.as_ptr() as usize;
+            let end = last.text.as_ptr() as usize - self.text.as_ptr() as usize + last.text.len();
+            // the first line knows where the paragraph starts
+            Some(Self {
+                text: &self.text[start..end],
+                ..first
+            })
+        })
+    }
+
+    /// Exactly `N` paragraphs, such as the sections of an input
+    pub fn sections<const N: usize>(self) -> Result<[Self; N], ParseError> {
+        let sections = self.paragraphs().collect::<Vec<_>>();
+        let found = sections.len();
+        sections.try_into().map_err(|_| {
+            self.trim()
+                .end()
+                .error(format!("expected {} sections, found {}", N, found))
+        })
+    }
+
+    pub fn split(self, separator: &'a str) -> impl Iterator<Item = Self> {
+        self.subs(self.text.split(separator))
+    }
+
+    /// The parts before and after the first separator
+    pub fn split_once(self, separator: &str) -> Result<(Self, Self), ParseError> {
+        match self.text.find(separator) {
+            Some(i) => Ok((
+                self.slice(0, i),
+                self.slice(i + separator.len(), self.text.len()),
+            )),
+            None => Err(self.end().error(format!("expected `{}`", separator))),
+        }
+    }
+
+    pub fn strip_prefix(self, prefix: &str) -> Result<Self, ParseError> {
+        match self.text.starts_with(prefix) {
+            true => Ok(self.slice(prefix.len(), self.text.len())),
+            false => Err(self.error(format!("expected `{}`", prefix))),
+        }
+    }
+
+    pub fn parse<T>(self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.text
+            .parse()
+            .map_err(|e| self.error(format!("cannot parse `{}`: {}", self.text, e)))
+    }
+
+    /// Every integer of the span, with its sign unless it follows a digit as in ranges like `1-3`
+    pub fn integers<T>(self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut pieces = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let signed = i > 0
+                && matches!(bytes[i - 1], b'-' | b'+')
+                && !(i > 1 && bytes[i - 2].is_ascii_digit());
+            let start = if signed { i - 1 } else { i };
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            pieces.push(&self.text[start..i]);
+        }
+        self.subs(pieces.into_iter())
+            .map(|integer| integer.parse())
+            .collect()
+    }
+
+    /// Lines of `key<separator>value`, both sides trimmed
+    pub fn key_values(self, separator: &str) -> Result<Vec<(&'a str, Self)>, ParseError> {
+        self.lines()
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| {
+                let (key, value) = line.split_once(separator)?;
+                Ok((key.trim().text, value.trim()))
+            })
+            .collect()
+    }
+
+    /// Map of cells, one row per line, `f` returning `None` for the characters it does not expect
+    pub fn grid<T>(self, mut f: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in self.lines() {
+            for (i, c) in line.text.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    line.slice(i, i + c.len_utf8())
+                        .error(format!("unexpected `{}`", c))
+                })?;
+                cells.push(cell);
+            }
+            let row_width = line.text.chars().count();
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(line.error(format!(
+                    "row is {} wide, the rows above are {} wide",
+                    row_width, width
+                )));
+            }
+            height += 1;
+        }
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    /// Matches the whole span against a template of literal text and `{}` placeholders, returning
+    /// the text of each placeholder. A placeholder takes everything up to the literal following it.
+    ///
+    /// Panics if the template does not have `N` placeholders or has two next to each other.
+    pub fn scan<const N: usize>(self, template: &str) -> Result<[Self; N], ParseError> {
+        let mut literals = template.split("{}").peekable();
+        let mut rest = self.strip_prefix(literals.next().unwrap())?;
+        let mut captures = Vec::with_capacity(N);
+        while let Some(literal) = literals.next() {
+            if literal.is_empty() {
+                assert!(
+                    literals.peek().is_none(),
+                    "placeholders of `{}` must be separated by text",
+                    template
+                );
+                captures.push(rest);
+                rest = rest.end();
+            } else {
+                let (capture, after) = rest.split_once(literal)?;
+                captures.push(capture);
+                rest = after;
+            }
+        }
+        if !rest.is_empty() {
+            return Err(rest.error(format!("unexpected `{}`", rest.text)));
+        }
+        let found = captures.len();
+        captures.try_into().map_err(|_| {
+            panic!(
+                "`{}` has {} placeholders, {} are expected",
+                template, found, N
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let input = Span::new("ab\ncdé f\n\nlast");
+        let lines = input.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!((lines[1].line(), lines[1].column()), (2, 1));
+        let (_, f) = lines[1].split_once(" ").unwrap();
+        assert_eq!((f.as_str(), f.line(), f.column()), ("f", 2, 5));
+        assert_eq!((input.end().line(), input.end().column()), (4, 5));
+        let error = lines[0].strip_prefix("x").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: expected `x`");
+        let error = lines[3].split_once(":").unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 5: expected `:`");
+        // pieces are positioned one after the other
+        let many = "ab 12\n".repeat(999) + "ab 300";
+        let many = Span::new(&many);
+        assert!(many
+            .lines()
+            .enumerate()
+            .all(|(i, line)| line.line() == i + 1));
+        let pieces = many.split(" ").collect::<Vec<_>>();
+        assert_eq!((pieces[1000].line(), pieces[1000].column()), (1000, 4));
+        let error = many.integers::<i8>().unwrap_err();
+        assert_eq!((error.line, error.column), (1000, 4));
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let input = Span::new("\na\nb\n\n\nc\n");
+        let paragraphs = input.paragraphs().collect::<Vec<_>>();
+        assert_eq!(paragraphs.len(), 2);
+        assert_eq!(paragraphs[0].as_str(), "a\nb");
+        assert_eq!((paragraphs[1].as_str(), paragraphs[1].line()), ("c", 6));
+        let [rules, _] = input.sections().unwrap();
+        assert_eq!(rules.line(), 2);
+        let error = input.sections::<3>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 6, column 2: expected 3 sections, found 2"
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        let line = Span::new("x=-12, y=+3..4 1-3 a");
+        assert_eq!(line.integers::<i32>().unwrap(), [-12, 3, 4, 1, 3]);
+        let error = line.integers::<u8>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: cannot parse `-12`: invalid digit found in string"
+        );
+        let error = Span::new("1 2 300").integers::<u8>().unwrap_err();
+        assert_eq!(error.column, 5);
+        assert!(Span::new("none").integers::<u8>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_key_values() {
+        let input = Span::new("a: 1\nbb :  x y\n");
+        let pairs = input.key_values(":").unwrap();
+        assert_eq!(pairs[0].0, "a");
+        assert_eq!(pairs[1].1.as_str(), "x y");
+        assert_eq!(pairs[1].1.column(), 7);
+        let error = Span::new("a: 1\nb").key_values(":").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    fn test_grid() {
+        let cell = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        let grid = Span::new("#.\n.#").grid(cell).unwrap();
+        assert_eq!(grid.cells(), [true, false, false, true]);
+        let error = Span::new("#.\n.x").grid(cell).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: unexpected `x`");
+        let error = Span::new("#.\n.").grid(cell).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: row is 1 wide, the rows above are 2 wide"
+        );
+    }
+
+    #[test]
+    fn test_scan() {
+        let line = Span::new("mem[8] = 11");
+        let [address, value] = line.scan("mem[{}] = {}").unwrap();
+        assert_eq!(address.parse::<u64>().unwrap(), 8);
+        assert_eq!((value.as_str(), value.column()), ("11", 10));
+        let [op, arg] = Span::new("jmp -4").scan("{} {}").unwrap();
+        assert_eq!((op.as_str(), arg.parse::<i32>().unwrap()), ("jmp", -4));
+        let error = Span::new("mem[8]").scan::<2>("mem[{}] = {}").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 7: expected `] = `");
+        let error = Span::new("mask = 1").scan::<2>("mem[{}] = {}").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: expected `mem[`");
+        let parts = Span::new("a-b!").scan::<2>("{}-{}!").unwrap();
+        assert_eq!(parts.map(|s| s.as_str()), ["a", "b"]);
+        let error = Span::new("a-b!?").scan::<2>("{}-{}!").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 5: unexpected `?`");
+    }
+
+    #[test]
+    #[should_panic(expected = "has 2 placeholders, 3 are expected")]
+    fn test_scan_placeholders() {
+        let _ = Span::new("a b").scan::<3>("{} {}");
+    }
+}
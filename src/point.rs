@@ -0,0 +1,351 @@
+//! Points and vectors of any dimension, and the directions and rotations puzzles move them with.
+//!
+//! In 2D `y` grows downwards, as the rows of a [`Grid`](crate::grid::Grid) do, so `Up` is `-y`.
+
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [i64; N]);
+
+pub type P2 = Point<2>;
+pub type P3 = Point<3>;
+pub type P4 = Point<4>;
+
+impl<const N: usize> Point<N> {
+    pub const ORIGIN: Self = Self([0; N]);
+
+    pub fn x(self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(self) -> i64 {
+        self.0[1]
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self - other).0.iter().map(|d| d.abs()).sum()
+    }
+
+    /// Distance when moving diagonally costs as much as moving straight
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self - other).0.iter().map(|d| d.abs()).max().unwrap_or(0)
+    }
+
+    /// Vector of the signs of the coordinates, a single step towards `self` from the origin
+    pub fn signum(self) -> Self {
+        Self(self.0.map(i64::signum))
+    }
+
+    /// The `2 * N` points one step away along an axis
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item = Self> {
+        (0..N).flat_map(move |axis| {
+            [-1, 1].map(|d| {
+                let mut p = self;
+                p.0[axis] += d;
+                p
+            })
+        })
+    }
+
+    /// The `3^N - 1` points around, diagonals included
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        (0..3usize.pow(N as u32))
+            .map(move |mut n| {
+                let mut p = self;
+                for c in &mut p.0 {
+                    *c += (n % 3) as i64 - 1;
+                    n /= 3;
+                }
+                p
+            })
+            .filter(move |&p| p != self)
+    }
+}
+
+impl P2 {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self([x, y])
+    }
+
+    /// Quarter turn of the vector, counterclockwise on screen
+    pub fn turn_left(self) -> Self {
+        Self::new(self.y(), -self.x())
+    }
+
+    /// Quarter turn of the vector, clockwise on screen
+    pub fn turn_right(self) -> Self {
+        Self::new(-self.y(), self.x())
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+            Turn::Around => -self,
+        }
+    }
+}
+
+impl P3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self([x, y, z])
+    }
+
+    pub fn z(self) -> i64 {
+        self.0[2]
+    }
+
+    /// One of the 24 orientations of the axes, `0` leaving the point as it is
+    pub fn rotate(self, rotation: u8) -> Self {
+        let [x, y, z] = self.0;
+        Self(match rotation {
+            0 => [x, y, z],
+            1 => [x, z, -y],
+            2 => [x, -y, -z],
+            3 => [x, -z, y],
+            4 => [y, x, -z],
+            5 => [y, z, x],
+            6 => [y, -x, z],
+            7 => [y, -z, -x],
+            8 => [z, x, y],
+            9 => [z, y, -x],
+            10 => [z, -x, -y],
+            11 => [z, -y, x],
+            12 => [-x, y, -z],
+            13 => [-x, z, y],
+            14 => [-x, -y, z],
+            15 => [-x, -z, -y],
+            16 => [-y, x, z],
+            17 => [-y, z, -x],
+            18 => [-y, -x, -z],
+            19 => [-y, -z, x],
+            20 => [-z, x, -y],
+            21 => [-z, y, x],
+            22 => [-z, -x, y],
+            23 => [-z, -y, -x],
+            _ => panic!("there are 24 rotations, not {}", rotation + 1),
+        })
+    }
+
+    /// The point in each of the 24 orientations of the axes
+    pub fn rotations(self) -> impl Iterator<Item = Self> {
+        (0..24).map(move |rotation| self.rotate(rotation))
+    }
+}
+
+impl P4 {
+    pub fn new(x: i64, y: i64, z: i64, w: i64) -> Self {
+        Self([x, y, z, w])
+    }
+
+    pub fn z(self) -> i64 {
+        self.0[2]
+    }
+
+    pub fn w(self) -> i64 {
+        self.0[3]
+    }
+}
+
+impl<const N: usize> From<[i64; N]> for Point<N> {
+    fn from(coords: [i64; N]) -> Self {
+        Self(coords)
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &i64 {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut i64 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, other: Self) {
+        self.0.iter_mut().zip(other.0).for_each(|(c, d)| *c += d);
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, other: Self) {
+        self.0.iter_mut().zip(other.0).for_each(|(c, d)| *c -= d);
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|c| -c))
+    }
+}
+
+impl<const N: usize> Mul<i64> for Point<N> {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self(self.0.map(|c| c * factor))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Around,
+}
+
+/// Directions on a map, clockwise from `Up`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parses an arrow (`^>v<`), a letter of `URDL` or a compass point of `NESW`
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'U' | 'N' => Some(Direction::Up),
+            '>' | 'R' | 'E' => Some(Direction::Right),
+            'v' | 'D' | 'S' => Some(Direction::Down),
+            '<' | 'L' | 'W' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The step of one cell in the direction
+    pub fn offset(self) -> P2 {
+        match self {
+            Direction::Up => P2::new(0, -1),
+            Direction::Right => P2::new(1, 0),
+            Direction::Down => P2::new(0, 1),
+            Direction::Left => P2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_around(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+            Turn::Around => self.turn_around(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (P3::new(1, -2, 3), P3::new(4, 5, -6));
+        assert_eq!(a + b, P3::new(5, 3, -3));
+        assert_eq!(a - b, P3::new(-3, -7, 9));
+        assert_eq!(-a * 2, P3::new(-2, 4, -6));
+        assert_eq!(a.manhattan(b), 19);
+        assert_eq!(a.chebyshev(b), 9);
+        assert_eq!((b - a).signum(), P3::new(1, 1, -1));
+        let mut c = P4::ORIGIN;
+        c += P4::new(1, 2, 3, 4);
+        c[3] -= 5;
+        assert_eq!((c.x(), c.y(), c.z(), c.w()), (1, 2, 3, -1));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = P2::new(3, 3);
+        assert_eq!(p.neighbours().count(), 8);
+        assert!(p.neighbours().all(|n| p.chebyshev(n) == 1));
+        let mut orthogonal = p.orthogonal_neighbours().collect::<Vec<_>>();
+        orthogonal.sort_unstable();
+        let expected = Direction::ALL.map(|d| p + d.offset());
+        assert!(expected.iter().all(|n| orthogonal.contains(n)));
+        assert_eq!(P3::ORIGIN.neighbours().count(), 26);
+        assert_eq!(P4::ORIGIN.neighbours().count(), 80);
+        assert_eq!(P4::ORIGIN.orthogonal_neighbours().count(), 8);
+    }
+
+    #[test]
+    fn test_directions() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn(Turn::Around), d.turn_right().turn_right());
+            assert_eq!(d.turn_left().offset(), d.offset().turn_left());
+            assert_eq!(d.turn_right().offset(), d.offset().turn_right());
+            assert_eq!(d.turn_around().offset(), d.offset().turn(Turn::Around));
+        }
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('E'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('x'), None);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let p = P3::new(1, 2, 3);
+        let mut rotated = p.rotations().collect::<Vec<_>>();
+        rotated.sort_unstable();
+        rotated.dedup();
+        assert_eq!(rotated.len(), 24);
+        // rotations keep the axes right handed: x × y = z
+        let cross = |a: P3, b: P3| {
+            P3::new(
+                a.y() * b.z() - a.z() * b.y(),
+                a.z() * b.x() - a.x() * b.z(),
+                a.x() * b.y() - a.y() * b.x(),
+            )
+        };
+        for r in 0..24 {
+            let [x, y, z] =
+                [P3::new(1, 0, 0), P3::new(0, 1, 0), P3::new(0, 0, 1)].map(|a| a.rotate(r));
+            assert_eq!(cross(x, y), z);
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! Overview of the solved parts of every event, to see which years and days are left to do.
+
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    check::KnownAnswers,
+    client::{self, AOC_URL},
+    util::Error,
+    Registry,
+};
+
+/// The first event
+pub const FIRST_YEAR: i32 = 2015;
+
+lazy_static! {
+    static ref CALENDAR_DAY: Regex =
+        Regex::new(r#"aria-label="Day (\d+)(?:, (one|two) stars?)?""#).unwrap();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    /// The answer is in the known answers
+    Known,
+    /// Solved on the site, but its answer is not known
+    Star,
+    /// The day is implemented, the part is not solved
+    Implemented,
+    /// The day is not implemented
+    Missing,
+}
+
+impl Part {
+    fn new(implemented: bool, known: bool, star: bool) -> Self {
+        match (known, star, implemented) {
+            (true, _, _) => Part::Known,
+            (false, true, _) => Part::Star,
+            (false, false, true) => Part::Implemented,
+            (false, false, false) => Part::Missing,
+        }
+    }
+
+    fn is_solved(self) -> bool {
+        matches!(self, Part::Known | Part::Star)
+    }
+
+    fn symbol(self) -> char {
+        match self {
+            Part::Known => '*',
+            Part::Star => '+',
+            Part::Implemented => '.',
+            Part::Missing => '-',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearProgress {
+    pub year: i32,
+    /// Both parts of every day of the event
+    pub days: Vec<[Part; 2]>,
+    pub implemented: usize,
+}
+
+impl YearProgress {
+    /// Combines the implemented days, the known answers and the stars of the calendar, if given
+    pub fn new(
+        year: i32,
+        implemented: &[u32],
+        known_answers: &KnownAnswers,
+        stars: Option<&BTreeMap<u32, u8>>,
+    ) -> Self {
+        let days = (1..=year_lib::event_days(year))
+            .map(|day| {
+                let stars = stars.and_then(|stars| stars.get(&day)).copied();
+                [1, 2].map(|part| {
+                    Part::new(
+                        implemented.contains(&day),
+                        known_answers.get(day, part).is_some(),
+                        stars.is_some_and(|stars| stars >= part),
+                    )
+                })
+            })
+            .collect();
+        Self {
+            year,
+            days,
+            implemented: implemented.len(),
+        }
+    }
+
+    pub fn stars(&self) -> usize {
+        self.days
+            .iter()
+            .flatten()
+            .filter(|part| part.is_solved())
+            .count()
+    }
+
+    /// The days of December in weeks from Monday to Sunday, each day with its number and the
+    /// symbols of both parts
+    pub fn render(&self) -> String {
+        let mut text = format!(
+            "{}: {}/{} stars, ",
+            self.year,
+            self.stars(),
+            self.days.len() * 2
+        );
+        text += &match self.implemented {
+            0 => "not implemented\n".to_string(),
+            n => format!("{}/{} days implemented\n", n, self.days.len()),
+        };
+        let weekdays = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+        let header = weekdays.map(|weekday| format!("{:<5}", weekday)).join(" ");
+        text += header.trim_end();
+        text.push('\n');
+        let first = NaiveDate::from_ymd_opt(self.year, 12, 1).unwrap();
+        let mut week = vec![" ".repeat(5); first.weekday().num_days_from_monday() as usize];
+        for (day, [part1, part2]) in (1..).zip(&self.days) {
+            week.push(format!("{:>2} {}{}", day, part1.symbol(), part2.symbol()));
+            if week.len() == 7 || day == self.days.len() {
+                text += week.join(" ").trim_end();
+                text.push('\n');
+                week.clear();
+            }
+        }
+        text
+    }
+}
+
+/// Stars of every day of the event calendar page
+fn parse_calendar(page: &str) -> BTreeMap<u32, u8> {
+    CALENDAR_DAY
+        .captures_iter(page)
+        .filter_map(|captures| {
+            let stars = match captures.get(2).map(|stars| stars.as_str()) {
+                Some("two") => 2,
+                Some(_) => 1,
+                None => 0,
+            };
+            Some((captures[1].parse().ok()?, stars))
+        })
+        .collect()
+}
+
+fn fetch_stars(session: &str, year: i32) -> Result<BTreeMap<u32, u8>, Error> {
+    let page = client::get(&format!("{}/{}", AOC_URL, year), session)?;
+    Ok(parse_calendar(&page))
+}
+
+/// Prints the progress of the years, with the stars of their calendar pages if asked to.
+/// Returns false if the known answers or the stars could not be read.
+pub fn run(registry: &Registry, years: &[i32], with_stars: bool) -> bool {
+    let session = match with_stars.then(crate::util::get_session).transpose() {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+    println!("* known answer, + star without known answer, . implemented, - not implemented\n");
+    let (mut stars, mut total) = (0, 0);
+    let mut missing = Vec::new();
+    for &year in years {
+        let fetched = KnownAnswers::load(year).and_then(|known_answers| {
+            let calendar = session
+                .as_deref()
+                .map(|session| fetch_stars(session, year))
+                .transpose()?;
+            Ok((known_answers, calendar))
+        });
+        let (known_answers, calendar) = match fetched {
+            Ok(fetched) => fetched,
+            Err(e) => {
+                eprintln!("error: {}: {}", year, e);
+                return false;
+            }
+        };
+        let implemented = registry
+            .get(year)
+            .map_or(&[][..], |aoc_year| aoc_year.available_days());
+        let progress = YearProgress::new(year, implemented, &known_answers, calendar.as_ref());
+        println!("{}", progress.render());
+        stars += progress.stars();
+        total += progress.days.len() * 2;
+        if progress.implemented == 0 {
+            missing.push(year.to_string());
+        }
+    }
+    print!("total: {}/{} stars", stars, total);
+    match missing.is_empty() {
+        true => println!(),
+        false => println!(", not implemented: {}", missing.join(", ")),
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CALENDAR: &str = include_str!("../fixtures/calendar.html");
+
+    #[test]
+    fn test_parse_calendar() {
+        let stars = parse_calendar(CALENDAR);
+        assert_eq!(
+            stars.into_iter().collect::<Vec<_>>(),
+            [(1, 2), (2, 1), (3, 0), (4, 2)]
+        );
+    }
+
+    #[test]
+    fn test_year_progress() {
+        let known_answers =
+            KnownAnswers::parse("[day1]\npart1 = 1\npart2 = 2\n[day2]\npart1 = 3\n").unwrap();
+        let stars = parse_calendar(CALENDAR);
+        let progress = YearProgress::new(2021, &[1, 2, 3], &known_answers, Some(&stars));
+        assert_eq!(progress.days.len(), 25);
+        assert_eq!(progress.days[0], [Part::Known, Part::Known]);
+        assert_eq!(progress.days[1], [Part::Known, Part::Implemented]);
+        assert_eq!(progress.days[2], [Part::Implemented, Part::Implemented]);
+        assert_eq!(progress.days[3], [Part::Star, Part::Star]);
+        assert_eq!(progress.days[4], [Part::Missing, Part::Missing]);
+        assert_eq!(progress.stars(), 5);
+
+        let progress = YearProgress::new(2021, &[1, 2, 3], &known_answers, None);
+        assert_eq!(progress.days[3], [Part::Missing, Part::Missing]);
+        assert_eq!(progress.stars(), 3);
+    }
+
+    #[test]
+    fn test_render() {
+        let known_answers = KnownAnswers::parse("[day1]\npart1 = 1\npart2 = 2\n").unwrap();
+        let progress = YearProgress::new(2025, &[1, 2], &known_answers, None);
+        // December 2025 starts on a Monday
+        let render = progress.render();
+        assert_eq!(
+            render.lines().collect::<Vec<_>>(),
+            [
+                "2025: 2/24 stars, 2/12 days implemented",
+                "Mo    Tu    We    Th    Fr    Sa    Su",
+                " 1 **  2 ..  3 --  4 --  5 --  6 --  7 --",
+                " 8 --  9 -- 10 -- 11 -- 12 --",
+            ]
+        );
+        let progress = YearProgress::new(2019, &[], &KnownAnswers::default(), None);
+        let render = progress.render();
+        let lines = render.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "2019: 0/50 stars, not implemented");
+        // December 2019 starts on a Sunday
+        assert_eq!(lines[2], format!("{} 1 --", " ".repeat(36)));
+        assert_eq!(lines.len(), 7);
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::{
+    client::{self, AOC_URL},
+    util::Error,
+};
+
+const YEAR_TEMPLATE: &str = "use year_lib::AOCYear;
+
+#[derive(AOCYear)]
+pub struct AOC{year};
+";
+
+const DAY_TEMPLATE: &str = "use crate::util::{get_puzzle_input, Error};
+use year_lib::{Solution, Timer};
+
+pub(crate) fn run(timer: &mut Timer) -> Result<Solution, Error> {
+    let input = timer.input(|| get_puzzle_input({year}, {day}))?;
+    let lines = timer.parse(|| parse(&input));
+    let p1 = timer.part1(|| part1(&lines));
+    let p2 = timer.part2(|| part2(&lines));
+    Ok(Solution::new(p1, p2))
+}
+
+fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+fn part1(_lines: &[&str]) -> usize {
+    0
+}
+
+fn part2(_lines: &[&str]) -> usize {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_1() {
+        let input = crate::util::read_file(\"inputs/{year}_{day}_test.in\").unwrap();
+        let lines = parse(&input);
+        assert_eq!(part1(&lines), 0);
+    }
+
+    #[test]
+    fn test_2() {
+        let input = crate::util::read_file(\"inputs/{year}_{day}_test.in\").unwrap();
+        let lines = parse(&input);
+        assert_eq!(part2(&lines), 0);
+    }
+}
+";
+
+/// Creates the day from the template, and its year module if missing. Returns the created files.
+pub fn scaffold(root: &Path, year: i32, day: u32) -> Result<Vec<PathBuf>, Error> {
+    let mut created = Vec::new();
+    let year_dir = root.join("src").join(format!("aoc{}", year));
+    let year_mod = year_dir.join("mod.rs");
+    let day_file = year_dir.join(format!("day{}.rs", day));
+    if day_file.exists() {
+        return Err(io_error(&day_file, io::ErrorKind::AlreadyExists.into()));
+    }
+    if !year_mod.exists() {
+        write(&year_mod, &render(YEAR_TEMPLATE, year, day))?;
+        created.push(year_mod.clone());
+        // `aoc_years!` lists the years, lib.rs has to be rebuilt
+        touch(&root.join("src").join("lib.rs"))?;
+    }
+    write(&day_file, &render(DAY_TEMPLATE, year, day))?;
+    created.push(day_file);
+    // the derive macro lists the days of the year, the year module has to be rebuilt
+    touch(&year_mod)?;
+    Ok(created)
+}
+
+/// Updates the modification time of a file, so that cargo rebuilds it
+fn touch(path: &Path) -> Result<(), Error> {
+    File::options()
+        .append(true)
+        .open(path)
+        .and_then(|f| f.set_modified(SystemTime::now()))
+        .map_err(|e| io_error(path, e))
+}
+
+fn render(template: &str, year: i32, day: u32) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    crate::util::write_to_file(contents.as_bytes(), path)
+}
+
+fn io_error(path: &Path, source: io::Error) -> Error {
+    Error::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+/// First example of the puzzle page
+fn extract_example(html: &str) -> Option<String> {
+    crate::describe::extract_examples(html).into_iter().next()
+}
+
+/// Saves the example of the puzzle page as the test input of the day. Returns the created file,
+/// if the example was not saved before.
+fn download_example(root: &Path, year: i32, day: u32) -> Result<Option<PathBuf>, Error> {
+    let path = root
+        .join("inputs")
+        .join(format!("{}_{}_test.in", year, day));
+    if path.exists() {
+        return Ok(None);
+    }
+    crate::unlock::check(year, day)?;
+    let session = crate::util::get_session()?;
+    let page = client::get(&format!("{}/{}/day/{}", AOC_URL, year, day), &session)?;
+    match extract_example(&page) {
+        Some(example) => {
+            write(&path, &example)?;
+            Ok(Some(path))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Scaffolds the day in the repository, then downloads its input and example when
+/// available. Returns false if the day could not be created.
+pub fn run(year: i32, day: u32) -> bool {
+    if !(1..=year_lib::event_days(year)).contains(&day) {
+        eprintln!(
+            "error: the {} event has {} days",
+            year,
+            year_lib::event_days(year)
+        );
+        return false;
+    }
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    match scaffold(root, year, day) {
+        Ok(created) => created
+            .iter()
+            .for_each(|path| println!("created {}", path.display())),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    }
+    match crate::util::fetch_puzzle_input(year as u32, day as u8) {
+        Ok(_) => println!("input of {} day {} is cached", year, day),
+        Err(e) => eprintln!("warning: input not downloaded: {}", e),
+    }
+    match download_example(root, year, day) {
+        Ok(Some(path)) => println!("created {}", path.display()),
+        Ok(None) => {}
+        Err(e) => eprintln!("warning: example not downloaded: {}", e),
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        let lib = root.join("src").join("lib.rs");
+        fs::write(&lib, "year_lib::aoc_years!();\n").unwrap();
+        File::options()
+            .append(true)
+            .open(&lib)
+            .and_then(|f| f.set_modified(SystemTime::UNIX_EPOCH))
+            .unwrap();
+
+        let created = scaffold(&root, 2030, 1).unwrap();
+        let modified = fs::metadata(&lib).unwrap().modified().unwrap();
+        assert!(modified > SystemTime::UNIX_EPOCH);
+        assert_eq!(
+            created,
+            vec![
+                root.join("src/aoc2030/mod.rs"),
+                root.join("src/aoc2030/day1.rs")
+            ]
+        );
+        let day = fs::read_to_string(root.join("src/aoc2030/day1.rs")).unwrap();
+        assert!(day.contains("get_puzzle_input(2030, 1))?"));
+        assert!(day.contains("\"inputs/2030_1_test.in\""));
+
+        let created = scaffold(&root, 2030, 2).unwrap();
+        assert_eq!(created, vec![root.join("src/aoc2030/day2.rs")]);
+        assert!(scaffold(&root, 2030, 2).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_extract_example() {
+        let page = "<article><p>The input:</p><pre><code>not this</code></pre>\
+            <p>For example, suppose:</p><pre><code>1 &lt; <em>2</em>\n3 &amp; 4\n</code></pre>\
+            <p>Another example:</p><pre><code>nor this</code></pre></article>";
+        assert_eq!(extract_example(page), Some("1 < 2\n3 & 4\n".to_string()));
+        assert_eq!(
+            extract_example("<pre><code>only block</code></pre>"),
+            Some("only block".to_string())
+        );
+        assert_eq!(extract_example("<p>no code</p>"), None);
+    }
+}
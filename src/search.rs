@@ -0,0 +1,432 @@
+//! Searches over any kind of state, given the successors of a state and, for the weighted
+//! searches, the cost of each move.
+//!
+//! Paths are returned from the start to the goal, both included.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use fxhash::FxHashMap;
+
+/// No parent, for the start
+const ROOT: usize = usize::MAX;
+
+/// States met by a search, numbered in the order they were met, with the parent they were met from
+struct Visited<S> {
+    states: Vec<S>,
+    parents: Vec<usize>,
+    index: FxHashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new(start: S) -> Self {
+        let mut visited = Self {
+            states: Vec::new(),
+            parents: Vec::new(),
+            index: FxHashMap::default(),
+        };
+        visited.insert(start, ROOT);
+        visited
+    }
+
+    fn insert(&mut self, state: S, parent: usize) -> usize {
+        let i = self.states.len();
+        self.index.insert(state.clone(), i);
+        self.states.push(state);
+        self.parents.push(parent);
+        i
+    }
+
+    /// Path from the start to the i-th state
+    fn path(&self, mut i: usize) -> Vec<S> {
+        let mut path = Vec::new();
+        while i != ROOT {
+            path.push(self.states[i].clone());
+            i = self.parents[i];
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search of a shortest path to a goal, when every move costs the same
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let state = visited.states[i].clone();
+        if is_goal(&state) {
+            return Some(visited.path(i));
+        }
+        for next in successors(&state) {
+            if !visited.index.contains_key(&next) {
+                queue.push_back(visited.insert(next, i));
+            }
+        }
+    }
+    None
+}
+
+/// Breadth-first search of a shortest path between two states from both ends at once, the
+/// predecessors of a state being the states it is a successor of. Explores far fewer states
+/// than [`bfs`] when the goal is known.
+pub fn bidirectional_bfs<S, I, J>(
+    start: S,
+    goal: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut predecessors: impl FnMut(&S) -> J,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    J: IntoIterator<Item = S>,
+{
+    if start == goal {
+        return Some(vec![start]);
+    }
+    let mut forward = Visited::new(start);
+    let mut backward = Visited::new(goal);
+    let (mut forward_depths, mut backward_depths) = (vec![0], vec![0]);
+    let (mut forward_level, mut backward_level) = (vec![0], vec![0]);
+    while !forward_level.is_empty() && !backward_level.is_empty() {
+        // expands the smaller frontier by a whole level, and keeps the shortest of the paths met
+        let expand_forward = forward_level.len() <= backward_level.len();
+        let (visited, depths, level, other, other_depths) = match expand_forward {
+            true => (
+                &mut forward,
+                &mut forward_depths,
+                &mut forward_level,
+                &backward,
+                &backward_depths,
+            ),
+            false => (
+                &mut backward,
+                &mut backward_depths,
+                &mut backward_level,
+                &forward,
+                &forward_depths,
+            ),
+        };
+        let mut met: Option<(usize, usize, usize)> = None;
+        let mut next_level = Vec::new();
+        for &i in level.iter() {
+            let state = visited.states[i].clone();
+            let nexts = match expand_forward {
+                true => successors(&state).into_iter().collect::<Vec<_>>(),
+                false => predecessors(&state).into_iter().collect::<Vec<_>>(),
+            };
+            for next in nexts {
+                if visited.index.contains_key(&next) {
+                    continue;
+                }
+                let other_i = other.index.get(&next).copied();
+                let j = visited.insert(next, i);
+                depths.push(depths[i] + 1);
+                next_level.push(j);
+                if let Some(other_i) = other_i {
+                    let length = depths[j] + other_depths[other_i];
+                    if met.is_none_or(|(best, _, _)| length < best) {
+                        met = Some((length, j, other_i));
+                    }
+                }
+            }
+        }
+        if let Some((_, j, other_i)) = met {
+            let (forward_i, backward_i) = match expand_forward {
+                true => (j, other_i),
+                false => (other_i, j),
+            };
+            let mut path = forward.path(forward_i);
+            let mut rest = backward.path(backward_i);
+            rest.pop();
+            path.extend(rest.into_iter().rev());
+            return Some(path);
+        }
+        *level = next_level;
+    }
+    None
+}
+
+/// Dijkstra's search of a cheapest path to a goal, returned with its cost. The successors come
+/// with the cost of moving to them.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// A* search of a cheapest path to a goal, returned with its cost. The successors come with the
+/// cost of moving to them, the heuristic must not overestimate the cost left to reach a goal.
+/// A state reached again by a cheaper path is searched again, which never happens when the
+/// heuristic is also consistent: it drops by no more than the cost of a move.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    let mut visited = Visited::new(start);
+    let mut costs = vec![C::default()];
+    let mut closed = vec![false];
+    while let Some(Reverse((_, i))) = heap.pop() {
+        if std::mem::replace(&mut closed[i], true) {
+            continue;
+        }
+        let state = visited.states[i].clone();
+        if is_goal(&state) {
+            return Some((visited.path(i), costs[i]));
+        }
+        for (next, step) in successors(&state) {
+            let cost = costs[i] + step;
+            let j = match visited.index.get(&next) {
+                Some(&j) if costs[j] <= cost => continue,
+                Some(&j) => {
+                    visited.parents[j] = i;
+                    costs[j] = cost;
+                    closed[j] = false;
+                    j
+                }
+                None => {
+                    costs.push(cost);
+                    closed.push(false);
+                    visited.insert(next, i)
+                }
+            };
+            heap.push(Reverse((cost + heuristic(&visited.states[j]), j)));
+        }
+    }
+    None
+}
+
+/// Every cheapest path to the goals, with their cost. Beware that there may be exponentially
+/// many of them.
+pub fn all_shortest_paths<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<Vec<S>>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+    let mut visited = Visited::new(start);
+    // every state a cheapest path comes from, instead of the single parent of `visited`
+    let mut parents = vec![Vec::new()];
+    let mut costs = vec![C::default()];
+    let mut closed = vec![false];
+    let mut goals = Vec::new();
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        if goals.first().is_some_and(|&goal| costs[goal] < cost) {
+            break;
+        }
+        if std::mem::replace(&mut closed[i], true) {
+            continue;
+        }
+        let state = visited.states[i].clone();
+        if is_goal(&state) {
+            goals.push(i);
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let cost = costs[i] + step;
+            match visited.index.get(&next) {
+                Some(&j) if closed[j] || costs[j] < cost => {}
+                Some(&j) if costs[j] == cost => parents[j].push(i),
+                Some(&j) => {
+                    parents[j] = vec![i];
+                    costs[j] = cost;
+                    heap.push(Reverse((cost, j)));
+                }
+                None => {
+                    let j = visited.insert(next, i);
+                    parents.push(vec![i]);
+                    costs.push(cost);
+                    closed.push(false);
+                    heap.push(Reverse((cost, j)));
+                }
+            }
+        }
+    }
+    let cost = costs[*goals.first()?];
+    let mut paths = Vec::new();
+    // depth first from the goals back to the start, the path being built in reverse
+    let mut stack = goals.iter().map(|&goal| vec![goal]).collect::<Vec<_>>();
+    while let Some(path) = stack.pop() {
+        let last = *path.last().unwrap();
+        if last == 0 {
+            paths.push(
+                path.iter()
+                    .rev()
+                    .map(|&i| visited.states[i].clone())
+                    .collect(),
+            );
+            continue;
+        }
+        for &parent in &parents[last] {
+            let mut path = path.clone();
+            path.push(parent);
+            stack.push(path);
+        }
+    }
+    Some((paths, cost))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, |c| c)
+    }
+
+    fn moves(maze: &Grid<char>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        maze.neighbours4(pos).filter(|&n| maze[n] != '#').collect()
+    }
+
+    fn is_path(maze: &Grid<char>, path: &[(usize, usize)]) -> bool {
+        path.first() == maze.find(|&c| c == 'S').as_ref()
+            && path.last() == maze.find(|&c| c == 'E').as_ref()
+            && path.windows(2).all(|w| moves(maze, w[0]).contains(&w[1]))
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let path = bfs((0, 0), |&pos| moves(&maze, pos), |&pos| maze[pos] == 'E').unwrap();
+        assert_eq!(path.len(), 16);
+        assert!(is_path(&maze, &path));
+        assert_eq!(bfs((0, 0), |&pos| moves(&maze, pos), |_| false), None);
+        assert_eq!(bfs(3, |_| Vec::new(), |&n| n == 3), Some(vec![3]));
+    }
+
+    #[test]
+    fn test_bidirectional_bfs() {
+        let maze = maze();
+        let path = bidirectional_bfs(
+            (0, 0),
+            (7, 4),
+            |&pos| moves(&maze, pos),
+            |&pos| moves(&maze, pos),
+        )
+        .unwrap();
+        assert_eq!(path.len(), 16);
+        assert!(is_path(&maze, &path));
+        // a one way chain, from both ends
+        let path = bidirectional_bfs(0, 5, |&n| [n + 1], |&n| (n > 0).then(|| n - 1)).unwrap();
+        assert_eq!(path, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(
+            bidirectional_bfs(
+                5,
+                0,
+                |&n: &u32| (n < 9).then_some(n + 1),
+                |&n| n.checked_sub(1)
+            ),
+            None
+        );
+        assert_eq!(bidirectional_bfs(2, 2, |_| [], |_| []), Some(vec![2]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // going through the cheap cell is longer but cheaper
+        let costs = Grid::parse("191\n111", |c| c.to_digit(10).unwrap());
+        let successors = |&pos: &(usize, usize)| {
+            costs
+                .neighbours4(pos)
+                .map(|n| (n, costs[n]))
+                .collect::<Vec<_>>()
+        };
+        let (path, cost) = dijkstra((0, 0), successors, |&pos| pos == (2, 0)).unwrap();
+        assert_eq!(
+            (path, cost),
+            (vec![(0, 0), (0, 1), (1, 1), (2, 1), (2, 0)], 4)
+        );
+        assert_eq!(
+            dijkstra((0, 0), successors, |_| false),
+            None::<(Vec<_>, u32)>
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let maze = maze();
+        let goal: (usize, usize) = (7, 4);
+        let successors = |&pos: &(usize, usize)| moves(&maze, pos).into_iter().map(|n| (n, 1));
+        let heuristic = |&(x, y): &(usize, usize)| goal.0.abs_diff(x) + goal.1.abs_diff(y);
+        let (path, cost) = astar((0, 0), successors, heuristic, |&pos| pos == goal).unwrap();
+        assert_eq!(cost, 15);
+        assert!(is_path(&maze, &path));
+
+        // the heuristic drops by 4 on the move from 'b' to 'a' of cost 1, 'a' is reached first
+        // by the dearer path
+        let successors = |&state: &char| match state {
+            's' => vec![('a', 4), ('b', 1)],
+            'b' => vec![('a', 1)],
+            'a' => vec![('g', 4)],
+            _ => vec![],
+        };
+        let heuristic = |&state: &char| if state == 'b' { 4 } else { 0 };
+        let found = astar('s', successors, heuristic, |&state| state == 'g');
+        assert_eq!(found, Some((vec!['s', 'b', 'a', 'g'], 6)));
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        let open = &Grid::filled(3, 3, '.');
+        let successors = |&pos: &(usize, usize)| {
+            // only right and down
+            [(1, 0), (0, 1)]
+                .into_iter()
+                .filter_map(move |delta| open.step(pos, delta))
+                .map(|n| (n, 1))
+        };
+        let (paths, cost) = all_shortest_paths((0, 0), successors, |&pos| pos == (2, 2)).unwrap();
+        assert_eq!(cost, 4);
+        assert_eq!(paths.len(), 6);
+        assert!(paths
+            .iter()
+            .all(|path| path.len() == 5 && path[0] == (0, 0)));
+        // the goals at the same cost all count
+        let (paths, cost) = all_shortest_paths((0, 0), successors, |&(x, y)| x + y == 2).unwrap();
+        assert_eq!((paths.len(), cost), (4, 2));
+        assert_eq!(
+            all_shortest_paths((0, 0), successors, |_| false),
+            None::<(Vec<Vec<_>>, usize)>
+        );
+    }
+}
@@ -0,0 +1,479 @@
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use year_lib::Answer;
+
+use crate::{
+    client::{self, AOC_URL},
+    util::Error,
+};
+
+lazy_static! {
+    static ref ARTICLE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]+>").unwrap();
+    static ref WAIT: Regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+/// Response of the server to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, the server asks to wait before trying again.
+    RateLimited(Duration),
+    /// The part is either already solved or not unlocked yet.
+    WrongLevel,
+    /// Text of a response that could not be recognised.
+    Unknown(String),
+}
+
+impl Outcome {
+    fn parse(html: &str) -> Self {
+        let article = ARTICLE
+            .captures(html)
+            .map_or(html, |c| c.get(1).unwrap().as_str());
+        let text = TAG.replace_all(article, "");
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if text.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if let Some(c) = WAIT.captures(&text) {
+            let minutes = c.get(1).map_or(0, |m| m.as_str().parse::<u64>().unwrap());
+            let seconds = c[2].parse::<u64>().unwrap();
+            Outcome::RateLimited(Duration::from_secs(minutes * 60 + seconds))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown(text.split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+    }
+
+    fn is_rejection(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+
+    fn to_token(&self) -> String {
+        match self {
+            Outcome::Correct => "correct".to_string(),
+            Outcome::Wrong => "wrong".to_string(),
+            Outcome::TooHigh => "too_high".to_string(),
+            Outcome::TooLow => "too_low".to_string(),
+            Outcome::RateLimited(wait) => format!("rate_limited:{}", wait.as_secs()),
+            Outcome::WrongLevel => "wrong_level".to_string(),
+            Outcome::Unknown(_) => "unknown".to_string(),
+        }
+    }
+
+    fn from_token(token: &str) -> Option<Self> {
+        match token {
+            "correct" => Some(Outcome::Correct),
+            "wrong" => Some(Outcome::Wrong),
+            "too_high" => Some(Outcome::TooHigh),
+            "too_low" => Some(Outcome::TooLow),
+            "wrong_level" => Some(Outcome::WrongLevel),
+            "unknown" => Some(Outcome::Unknown(String::new())),
+            _ => {
+                let secs = token.strip_prefix("rate_limited:")?.parse().ok()?;
+                Some(Outcome::RateLimited(Duration::from_secs(secs)))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "wrong (too high)"),
+            Outcome::TooLow => write!(f, "wrong (too low)"),
+            Outcome::RateLimited(wait) => write!(f, "rate limited, wait {}s", wait.as_secs()),
+            Outcome::WrongLevel => write!(f, "part already solved or not unlocked"),
+            Outcome::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Record {
+    /// Seconds since the unix epoch
+    time: u64,
+    part: u8,
+    outcome: Outcome,
+    answer: String,
+}
+
+/// Past submissions of a day, one tab separated record per line:
+/// `{unix time}\t{part}\t{outcome}\t{answer}`
+#[derive(Debug)]
+pub struct Submissions {
+    path: PathBuf,
+    records: Vec<Record>,
+    /// Numbers of the lines that could not be read, they are left out
+    pub skipped: Vec<usize>,
+}
+
+impl Submissions {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let (records, skipped) = match path.exists() {
+            true => Self::parse(&crate::util::read_file(&path)?),
+            false => Default::default(),
+        };
+        Ok(Self {
+            path,
+            records,
+            skipped,
+        })
+    }
+
+    /// The records of the lines that can be read, and the numbers of the other lines
+    fn parse(contents: &str) -> (Vec<Record>, Vec<usize>) {
+        let mut records = Vec::new();
+        let mut skipped = Vec::new();
+        for (n, line) in (1..).zip(contents.lines()) {
+            if line.is_empty() {
+                continue;
+            }
+            match Self::parse_record(line) {
+                Some(record) => records.push(record),
+                None => skipped.push(n),
+            }
+        }
+        (records, skipped)
+    }
+
+    fn parse_record(line: &str) -> Option<Record> {
+        let mut fields = line.splitn(4, '\t');
+        Some(Record {
+            time: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            outcome: Outcome::from_token(fields.next()?)?,
+            answer: fields.next()?.to_string(),
+        })
+    }
+
+    /// Returns the reason not to submit the answer, if the past submissions already tell
+    /// how the server would respond.
+    pub fn refusal(&self, part: u8, answer: &str, now: u64) -> Option<String> {
+        let records = self.records.iter().filter(|r| r.part == part);
+        if let Some(r) = records.clone().find(|r| r.outcome == Outcome::Correct) {
+            return Some(format!(
+                "part {} was already solved with {}",
+                part, r.answer
+            ));
+        }
+        if let Some(r) = records
+            .clone()
+            .find(|r| r.answer == answer && r.outcome.is_rejection())
+        {
+            return Some(format!("{} was already submitted: {}", answer, r.outcome));
+        }
+        if let Ok(n) = answer.parse::<i128>() {
+            for r in records {
+                let Ok(m) = r.answer.parse::<i128>() else {
+                    continue;
+                };
+                match r.outcome {
+                    Outcome::TooHigh if n >= m => {
+                        return Some(format!("{} is too high, {} already was", n, m))
+                    }
+                    Outcome::TooLow if n <= m => {
+                        return Some(format!("{} is too low, {} already was", n, m))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        match self.records.last() {
+            Some(Record {
+                time,
+                outcome: Outcome::RateLimited(wait),
+                ..
+            }) if time + wait.as_secs() > now => Some(format!(
+                "rate limited, wait {}s",
+                time + wait.as_secs() - now
+            )),
+            _ => None,
+        }
+    }
+
+    pub fn is_solved(&self, part: u8) -> bool {
+        self.records
+            .iter()
+            .any(|r| r.part == part && r.outcome == Outcome::Correct)
+    }
+
+    fn record(&mut self, record: Record) -> Result<(), Error> {
+        let io_error = |source| Error::Io {
+            path: self.path.clone(),
+            source,
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}",
+            record.time,
+            record.part,
+            record.outcome.to_token(),
+            record.answer
+        )
+        .map_err(io_error)?;
+        self.records.push(record);
+        Ok(())
+    }
+}
+
+/// Posts the answer of a part and parses the response of the server
+fn post_answer(
+    base_url: &str,
+    session: &str,
+    year: i32,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, Error> {
+    let url = format!("{}/{}/day/{}/answer", base_url, year, day);
+    let fields = format!("level={}&answer={}", part, client::encode(answer));
+    Ok(Outcome::parse(&client::post(&url, session, &fields)?))
+}
+
+/// Submits the answer unless the past submissions of the day already rule it out,
+/// then records the outcome.
+pub fn submit(
+    base_url: &str,
+    session: &str,
+    submissions: &mut Submissions,
+    (year, day, part): (i32, u32, u8),
+    answer: &str,
+) -> Result<Outcome, String> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    if let Some(reason) = submissions.refusal(part, answer, now) {
+        return Err(reason);
+    }
+    let outcome =
+        post_answer(base_url, session, year, day, part, answer).map_err(|e| e.to_string())?;
+    let record = submissions.record(Record {
+        time: now,
+        part,
+        outcome: outcome.clone(),
+        answer: answer.to_string(),
+    });
+    if let Err(e) = record {
+        // the server has answered already, its outcome is still worth reporting
+        eprintln!("warning: the submission is not recorded: {}", e);
+    }
+    Ok(outcome)
+}
+
+/// Returns the session and its past submissions of the day, kept next to its inputs
+fn load_account_submissions(year: i32, day: u32) -> Result<(String, Submissions), Error> {
+    let session = crate::util::get_session()?;
+    let dir = crate::util::account_dir(&session)?;
+    let submissions = Submissions::load(dir.join(format!("{}_{}.submissions", year, day)))?;
+    if !submissions.skipped.is_empty() {
+        let lines = submissions.skipped.iter().map(|n| n.to_string());
+        eprintln!(
+            "warning: {}: unreadable lines left out: {}",
+            submissions.path.display(),
+            lines.collect::<Vec<_>>().join(", ")
+        );
+    }
+    Ok((session, submissions))
+}
+
+/// Submits a computed answer and prints the outcome. Returns true if the answer was correct.
+pub fn run(year: i32, day: u32, part: u8, answer: &Answer) -> bool {
+    if matches!(answer, Answer::Unsolved) || answer.is_multiline() {
+        eprintln!(
+            "{} day {} part {} has no single line answer to submit",
+            year, day, part
+        );
+        return false;
+    }
+    let (session, mut submissions) = match load_account_submissions(year, day) {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+    let answer = answer.to_string();
+    match submit(
+        AOC_URL,
+        &session,
+        &mut submissions,
+        (year, day, part),
+        &answer,
+    ) {
+        Ok(outcome) => {
+            println!(
+                "{} day {} part {}: {} is {}",
+                year, day, part, answer, outcome
+            );
+            outcome == Outcome::Correct
+        }
+        Err(reason) => {
+            eprintln!(
+                "{} day {} part {}: not submitted, {}",
+                year, day, part, reason
+            );
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::serve_once;
+
+    fn temp_submissions(name: &str) -> Submissions {
+        let path =
+            std::env::temp_dir().join(format!("aoc_{}_{}.submissions", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        Submissions::load(path).unwrap()
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer!  You are <span>one gold star</span> closer."
+            )),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer; your answer is too high.  Please wait one minute before trying again.")),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.")),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.")),
+            Outcome::RateLimited(Duration::from_secs(65))
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You gave an answer too recently.  You have 30s left to wait."
+            )),
+            Outcome::RateLimited(Duration::from_secs(30))
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::WrongLevel
+        );
+        assert_eq!(
+            Outcome::parse("<html>Internal error</html>"),
+            Outcome::Unknown("Internal error".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_submissions() {
+        let (records, skipped) = Submissions::parse(
+            "100\t1\ttoo_high\t500\n\
+             garbage\n\
+             200\t1\trate_limited:x\t400\n\
+             300\t2\trate_limited:60\t1 2\n\
+             400\t2\tmaybe\t7\n",
+        );
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[1].outcome,
+            Outcome::RateLimited(Duration::from_secs(60))
+        );
+        assert_eq!(records[1].answer, "1 2");
+        assert_eq!(skipped, [2, 3, 5]);
+    }
+
+    #[test]
+    fn test_refusal() {
+        let mut submissions = temp_submissions("refusal");
+        let mut record = |time, part, outcome, answer: &str| {
+            submissions
+                .record(Record {
+                    time,
+                    part,
+                    outcome,
+                    answer: answer.to_string(),
+                })
+                .unwrap()
+        };
+        record(100, 1, Outcome::TooHigh, "500");
+        record(200, 1, Outcome::TooLow, "100");
+        record(300, 1, Outcome::Wrong, "abc");
+        record(400, 2, Outcome::Correct, "7");
+        record(500, 1, Outcome::RateLimited(Duration::from_secs(60)), "300");
+        let submissions = Submissions::load(&submissions.path).unwrap();
+        assert_eq!(submissions.records.len(), 5);
+        assert!(submissions.refusal(1, "300", 520).is_some());
+        assert_eq!(submissions.refusal(1, "300", 600), None);
+        assert!(submissions.refusal(1, "500", 600).is_some());
+        assert!(submissions.refusal(1, "600", 600).is_some());
+        assert!(submissions.refusal(1, "50", 600).is_some());
+        assert!(submissions.refusal(1, "abc", 600).is_some());
+        assert!(submissions.refusal(2, "8", 600).is_some());
+        fs::remove_file(&submissions.path).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = serve_once(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        );
+        let mut submissions = temp_submissions("submit");
+        let outcome = submit(&base_url, "abc", &mut submissions, (2021, 3, 2), "42");
+        assert_eq!(outcome, Ok(Outcome::TooLow));
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2021/day/3/answer HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+        assert!(request.ends_with("level=2&answer=42"));
+
+        // the server is gone, a refused submission never reaches it
+        let outcome = submit(&base_url, "abc", &mut submissions, (2021, 3, 2), "42");
+        assert!(outcome.is_err());
+        assert_eq!(
+            Submissions::load(&submissions.path).unwrap().records.len(),
+            1
+        );
+        fs::remove_file(&submissions.path).unwrap();
+    }
+}
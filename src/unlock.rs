@@ -0,0 +1,164 @@
+//! Puzzles unlock at midnight US Eastern time (UTC-5) on the days of December.
+
+use std::{io::Write, thread, time::Duration};
+
+use chrono::{DateTime, Datelike, FixedOffset, TimeDelta, TimeZone, Utc};
+
+use crate::util::Error;
+
+/// Time zone of the unlocks, AoC does not follow daylight saving time
+pub fn eastern() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+/// Fails if the event of the year has no such day
+pub fn unlock_time(year: i32, day: u32) -> Result<DateTime<Utc>, Error> {
+    if !(1..=year_lib::event_days(year)).contains(&day) {
+        return Err(Error::NoSuchDay { year, day });
+    }
+    eastern()
+        .with_ymd_and_hms(year, 12, day, 0, 0, 0)
+        .single()
+        .map(|unlock| unlock.to_utc())
+        .ok_or(Error::NoSuchDay { year, day })
+}
+
+/// Last unlocked puzzle at the given time
+pub fn latest_puzzle(now: DateTime<Utc>) -> (i32, u32) {
+    let now = now.with_timezone(&eastern());
+    match now.month() {
+        12 => (now.year(), now.day().min(year_lib::event_days(now.year()))),
+        _ => (now.year() - 1, year_lib::event_days(now.year() - 1)),
+    }
+}
+
+/// Next puzzle to unlock after the given time
+pub fn next_puzzle(now: DateTime<Utc>) -> (i32, u32) {
+    let (year, day) = latest_puzzle(now);
+    match now.with_timezone(&eastern()).year() == year {
+        true if day < year_lib::event_days(year) => (year, day + 1),
+        _ => (year + 1, 1),
+    }
+}
+
+/// Fails with the time left before the puzzle unlocks, if it is still locked
+pub fn check(year: i32, day: u32) -> Result<(), Error> {
+    let unlock = unlock_time(year, day)?;
+    match Utc::now() < unlock {
+        true => Err(Error::Locked(unlock)),
+        false => Ok(()),
+    }
+}
+
+/// Time left as `1d 2h 3m 4s`, omitting the leading zero units
+pub fn format_duration(left: TimeDelta) -> String {
+    let seconds = left.num_seconds().max(0);
+    let units = [
+        (seconds / 86400, "d"),
+        (seconds / 3600 % 24, "h"),
+        (seconds / 60 % 60, "m"),
+        (seconds % 60, "s"),
+    ];
+    let first = units.iter().position(|&(n, _)| n > 0).unwrap_or(3);
+    units[first..]
+        .iter()
+        .map(|(n, unit)| format!("{}{}", n, unit))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Sleeps until the puzzle unlocks, counting down on stderr
+pub fn wait(year: i32, day: u32) -> Result<(), Error> {
+    let unlock = unlock_time(year, day)?;
+    loop {
+        let left = unlock - Utc::now();
+        if left <= TimeDelta::zero() {
+            break;
+        }
+        eprint!(
+            "\r\x1b[K{} day {} unlocks in {}",
+            year,
+            day,
+            format_duration(left)
+        );
+        let _ = std::io::stderr().flush();
+        // wakes up on the second of the unlock
+        let sleep = match left.num_milliseconds() % 1000 {
+            0 => 1000,
+            ms => ms,
+        };
+        thread::sleep(Duration::from_millis(sleep as u64));
+    }
+    eprintln!("\r\x1b[K{} day {} is unlocked", year, day);
+    Ok(())
+}
+
+/// Fetches the input of a puzzle that just unlocked, retrying while the server does not
+/// consider it unlocked yet
+pub fn fetch_input(year: i32, day: u32) -> Result<String, Error> {
+    let mut tries = 10;
+    loop {
+        match crate::util::fetch_puzzle_input(year as u32, day as u8) {
+            Err(Error::NotUnlocked | Error::Locked(_)) if tries > 1 => {
+                tries -= 1;
+                thread::sleep(Duration::from_secs(1));
+            }
+            fetched => return fetched,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2021, 1).unwrap(), utc("2021-12-01T05:00:00Z"));
+        assert_eq!(unlock_time(2025, 12).unwrap(), utc("2025-12-12T05:00:00Z"));
+        for (year, day) in [(2021, 0), (2021, 26), (2025, 13), (2025, 32)] {
+            assert!(matches!(
+                unlock_time(year, day),
+                Err(Error::NoSuchDay { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_latest_puzzle() {
+        assert_eq!(latest_puzzle(utc("2021-12-01T04:59:59Z")), (2020, 25));
+        assert_eq!(latest_puzzle(utc("2021-12-01T05:00:00Z")), (2021, 1));
+        assert_eq!(latest_puzzle(utc("2021-12-26T03:00:00Z")), (2021, 25));
+        assert_eq!(latest_puzzle(utc("2022-01-01T04:00:00Z")), (2021, 25));
+        assert_eq!(latest_puzzle(utc("2025-12-20T12:00:00Z")), (2025, 12));
+        assert_eq!(latest_puzzle(utc("2025-06-01T12:00:00Z")), (2024, 25));
+        assert_eq!(latest_puzzle(utc("2026-06-01T12:00:00Z")), (2025, 12));
+    }
+
+    #[test]
+    fn test_next_puzzle() {
+        assert_eq!(next_puzzle(utc("2021-11-30T12:00:00Z")), (2021, 1));
+        assert_eq!(next_puzzle(utc("2021-12-01T04:59:59Z")), (2021, 1));
+        assert_eq!(next_puzzle(utc("2021-12-01T05:00:00Z")), (2021, 2));
+        assert_eq!(next_puzzle(utc("2021-12-25T06:00:00Z")), (2022, 1));
+        assert_eq!(next_puzzle(utc("2025-12-12T05:00:00Z")), (2026, 1));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(TimeDelta::seconds(0)), "0s");
+        assert_eq!(format_duration(TimeDelta::seconds(65)), "1m 5s");
+        assert_eq!(format_duration(TimeDelta::seconds(3600)), "1h 0m 0s");
+        assert_eq!(format_duration(TimeDelta::seconds(90061)), "1d 1h 1m 1s");
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(check(2021, 1).is_ok());
+        assert!(matches!(check(9999, 1), Err(Error::Locked(_))));
+    }
+}
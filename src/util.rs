@@ -1,72 +1,582 @@
 use std::{
+    cell::RefCell,
     env,
-    fs::File,
-    io::{BufReader, BufWriter, Read, Write},
-    path::Path,
+    ffi::OsString,
+    fmt,
+    fs::{self, File, OpenOptions},
+    hash::Hasher,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    sync::RwLock,
+    thread,
+    time::Duration,
 };
 
-use chrono::{Datelike, Utc};
-use curl::easy::{Easy2, Handler, WriteError};
+use chrono::{DateTime, Utc};
 use dotenv::dotenv;
+use fnv::FnvHasher;
+use once_cell::sync::OnceCell;
 
-const USER_AGENT: &str = "github.com/gokberkkocak/adventofcode using libcurl";
+use crate::{
+    client::{self, AOC_URL},
+    parse::ParseError,
+};
+
+/// Inputs committed with the repository, used when no account is configured
+const BUNDLED_INPUTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/inputs");
+
+/// Failure to get or parse a puzzle input, or to talk with the server.
+#[derive(Debug)]
+pub enum Error {
+    /// `AOC_SESSION` is neither in the environment nor in `.env`
+    MissingSession,
+    /// The server answered with an unexpected status
+    Http {
+        status: u32,
+        message: String,
+    },
+    /// The server does not consider the puzzle unlocked yet
+    NotUnlocked,
+    /// The puzzle unlocks at the given time, the server was not asked
+    Locked(DateTime<Utc>),
+    /// The server answered with a body that could not be understood
+    InvalidResponse(String),
+    /// The event of the year does not have the day
+    NoSuchDay {
+        year: i32,
+        day: u32,
+    },
+    /// The puzzle input of a day could not be found nor downloaded
+    Input {
+        year: i32,
+        day: u32,
+        source: Box<Error>,
+    },
+    /// Neither `--cache-dir`, `AOC_CACHE_DIR` nor a home directory is available
+    NoCacheDir,
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// A file of the repository or of the cache directory has contents that cannot be read
+    InvalidFile {
+        path: PathBuf,
+        message: String,
+    },
+    Curl(curl::Error),
+    /// A day could not parse its input
+    Parse(ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "session credentials are missing, set AOC_SESSION in the environment or in .env"
+            ),
+            Error::Http { status, message } => write!(f, "HTTP {}: {}", status, message),
+            Error::NotUnlocked => write!(f, "puzzle is not unlocked yet"),
+            Error::Locked(unlock) => write!(
+                f,
+                "puzzle unlocks in {} ({}), use --wait to wait for it",
+                crate::unlock::format_duration(*unlock - Utc::now()),
+                unlock.format("%Y-%m-%d %H:%M:%S UTC")
+            ),
+            Error::InvalidResponse(message) => write!(f, "invalid response: {}", message),
+            Error::NoSuchDay { year, day } => write!(
+                f,
+                "the {} event has {} days, there is no day {}",
+                year,
+                year_lib::event_days(*year),
+                day
+            ),
+            Error::Input { year, day, source } => write!(f, "{} day {}: {}", year, day, source),
+            Error::NoCacheDir => write!(
+                f,
+                "no cache directory, use --cache-dir or set AOC_CACHE_DIR in the environment or in .env"
+            ),
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::InvalidFile { path, message } => write!(f, "{}: {}", path.display(), message),
+            Error::Curl(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "invalid input at {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<curl::Error> for Error {
+    fn from(e: curl::Error) -> Self {
+        Error::Curl(e)
+    }
+}
 
-struct Collector(Vec<u8>);
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+/// Returns the puzzle input, or the error of the day which could neither find nor download it
+pub fn get_puzzle_input(year: u32, day: u8) -> Result<String, Error> {
+    fetch_puzzle_input(year, day).map_err(|source| Error::Input {
+        year: year as i32,
+        day: day as u32,
+        source: Box::new(source),
+    })
+}
+
+/// Cache root given on the command line, takes precedence over `AOC_CACHE_DIR`
+static CACHE_DIR: OnceCell<PathBuf> = OnceCell::new();
+/// Contents replacing the puzzle input of any day, given with `--input` or `--example`
+static INPUT_OVERRIDE: RwLock<Option<String>> = RwLock::new(None);
+
+thread_local! {
+    /// Contents replacing the puzzle input of the days solved on this thread, given to the library
+    static GIVEN_INPUT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with the contents as the puzzle input of any day solved on this thread in the meantime
+pub(crate) fn with_input<T>(input: &str, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous input, even if the day panics
+    struct Restore(Option<String>);
 
-impl Handler for Collector {
-    fn write(&mut self, data: &[u8]) -> Result<usize, WriteError> {
-        self.0.extend_from_slice(data);
-        Ok(data.len())
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            GIVEN_INPUT.with(|given| *given.borrow_mut() = self.0.take());
+        }
     }
+
+    let _restore = Restore(GIVEN_INPUT.with(|given| given.replace(Some(input.to_string()))));
+    f()
+}
+
+pub fn set_cache_dir(dir: PathBuf) {
+    CACHE_DIR.set(dir).expect("Cache directory is already set");
 }
 
-/// Returns the puzzle input
-///
-pub fn get_puzzle_input(year: u32, day: u8) -> String {
-    let filename = format!("inputs/{}_{}.in", year, day);
-    if Path::new(&filename).exists() {
-        read_file(&filename)
+/// Reads the file, or stdin for `-`, to run the days against instead of their puzzle input
+pub fn set_input_override(path: &Path) -> Result<(), Error> {
+    let contents = if path == Path::new("-") {
+        let mut contents = String::new();
+        io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|source| Error::Io {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        contents.trim_end().to_string()
     } else {
-        dotenv().ok();
-        if let Ok(session) = env::var("AOC_SESSION") {
-            let mut easy = Easy2::new(Collector(Vec::new()));
-            easy.get(true).unwrap();
-            let input_url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
-            easy.url(&input_url).unwrap();
-            easy.cookie(&format!("session={}", session)).unwrap();
-            easy.useragent(USER_AGENT).unwrap();
-            easy.perform().unwrap();
-            let contents = easy.get_ref();
-            write_to_file(&contents.0, &filename);
-            return String::from_utf8_lossy(&contents.0).trim_end().to_string();
+        read_file(path)?
+    };
+    *INPUT_OVERRIDE.write().unwrap() = Some(contents);
+    Ok(())
+}
+
+/// Example inputs of a day committed with the repository, sorted by their number.
+/// `{year}_{day}_test.in` is the first example unless `{year}_{day}_test_1.in` exists.
+pub fn example_inputs(year: i32, day: u32) -> Result<Vec<(u32, PathBuf)>, Error> {
+    example_inputs_in(Path::new(BUNDLED_INPUTS), year, day)
+}
+
+/// Example inputs of a day in the given directory, sorted by their number
+pub fn example_inputs_in(dir: &Path, year: i32, day: u32) -> Result<Vec<(u32, PathBuf)>, Error> {
+    let entries = fs::read_dir(dir).map_err(|source| Error::Io {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+    let mut examples = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let filename = entry.file_name().into_string().ok()?;
+            let number = example_number(&filename, year, day)?;
+            Some((number, entry.path()))
+        })
+        .collect::<Vec<_>>();
+    // the unnumbered example is the first one, unless a numbered first one exists
+    let has_first = examples.iter().any(|(number, _)| *number == 1);
+    examples.retain_mut(|(number, _)| match number {
+        0 => {
+            *number = 1;
+            !has_first
+        }
+        _ => true,
+    });
+    examples.sort();
+    Ok(examples)
+}
+
+/// Number of the example input with the given filename, 0 for the unnumbered one
+fn example_number(filename: &str, year: i32, day: u32) -> Option<u32> {
+    let suffix = filename
+        .strip_prefix(&format!("{}_{}_test", year, day))?
+        .strip_suffix(".in")?;
+    match suffix {
+        "" => Some(0),
+        _ => suffix.strip_prefix('_')?.parse().ok(),
+    }
+}
+
+/// Returns the cached puzzle input, downloading it first if needed
+pub fn fetch_puzzle_input(year: u32, day: u8) -> Result<String, Error> {
+    if let Some(input) = GIVEN_INPUT.with(|given| given.borrow().clone()) {
+        return Ok(input);
+    }
+    if let Some(input) = INPUT_OVERRIDE.read().unwrap().as_ref() {
+        return Ok(input.clone());
+    }
+    let filename = format!("{}_{}.in", year, day);
+    let session = get_session().ok();
+    let dirs = input_dirs(cache_root()?, session_owner(session.as_deref()));
+    if let Some(path) = find_input(&dirs, &filename) {
+        return read_file(path);
+    }
+    let session = session.ok_or(Error::MissingSession)?;
+    crate::unlock::check(year as i32, day as u32)?;
+    let url = format!("{}/{}/day/{}/input", AOC_URL, year, day);
+    download_to_file(&url, &session, dirs[0].join(filename))
+}
+
+/// What became of an input when fetching it ahead of time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+    /// Cached, and checked to be the same on the server
+    Unchanged,
+    /// Cached, but downloaded again as it changed on the server
+    Updated,
+}
+
+impl fmt::Display for Fetched {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fetched::Cached => write!(f, "cached"),
+            Fetched::Downloaded => write!(f, "downloaded"),
+            Fetched::Unchanged => write!(f, "unchanged"),
+            Fetched::Updated => write!(f, "updated"),
+        }
+    }
+}
+
+/// Caches the input of the day if needed. With `refresh`, a cached input is downloaded again
+/// if the server has a newer one.
+pub fn prefetch_puzzle_input(year: i32, day: u32, refresh: bool) -> Result<Fetched, Error> {
+    let filename = format!("{}_{}.in", year, day);
+    let session = get_session()?;
+    let dirs = input_dirs(cache_root()?, session_owner(Some(&session)));
+    let url = format!("{}/{}/day/{}/input", AOC_URL, year, day);
+    let Some(path) = find_input(&dirs, &filename) else {
+        crate::unlock::check(year, day)?;
+        download_to_file(&url, &session, dirs[0].join(filename))?;
+        return Ok(Fetched::Downloaded);
+    };
+    if !refresh {
+        return Ok(Fetched::Cached);
+    }
+    let modified = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|source| Error::Io {
+            path: path.clone(),
+            source,
+        })?;
+    match client::get_if_modified(&url, &session, modified)? {
+        Some(contents) => {
+            let _lock = FileLock::acquire(&path)?;
+            write_to_file(contents.as_bytes(), &path)?;
+            Ok(Fetched::Updated)
+        }
+        None => Ok(Fetched::Unchanged),
+    }
+}
+
+fn find_input(dirs: &[PathBuf], filename: &str) -> Option<PathBuf> {
+    dirs.iter()
+        .map(|dir| dir.join(filename))
+        .find(|path| path.exists())
+}
+
+/// Directories to look for an input, the first one being where it gets downloaded.
+/// Inputs of an account are kept apart in a subfolder named after its owner, inputs found
+/// directly in the root are shared by every account.
+fn input_dirs(root: PathBuf, owner: Option<String>) -> Vec<PathBuf> {
+    match owner {
+        Some(owner) => vec![root.join(owner), root],
+        None => vec![root, PathBuf::from(BUNDLED_INPUTS)],
+    }
+}
+
+/// Returns the directory keeping the inputs and submissions of the account of the session
+pub fn account_dir(session: &str) -> Result<PathBuf, Error> {
+    Ok(input_dirs(cache_root()?, session_owner(Some(session))).swap_remove(0))
+}
+
+/// Cache root, from `--cache-dir`, `AOC_CACHE_DIR` (also looking into `.env`) or else
+/// `adventofcode` in the XDG cache directory
+pub fn cache_root() -> Result<PathBuf, Error> {
+    if let Some(dir) = CACHE_DIR.get() {
+        return Ok(dir.clone());
+    }
+    dotenv().ok();
+    match env::var_os("AOC_CACHE_DIR").filter(|dir| !dir.is_empty()) {
+        Some(dir) => Ok(PathBuf::from(dir)),
+        None => xdg_cache_dir(env::var_os("XDG_CACHE_HOME"), env::var_os("HOME"))
+            .map(|dir| dir.join("adventofcode"))
+            .ok_or(Error::NoCacheDir),
+    }
+}
+
+/// `$XDG_CACHE_HOME` if it is an absolute path, `$HOME/.cache` otherwise
+fn xdg_cache_dir(xdg_cache_home: Option<OsString>, home: Option<OsString>) -> Option<PathBuf> {
+    xdg_cache_home
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| {
+            home.filter(|home| !home.is_empty())
+                .map(|home| PathBuf::from(home).join(".cache"))
+        })
+}
+
+/// Owner of the session: `AOC_USER` (also looking into `.env`) or else a hash of the session
+fn session_owner(session: Option<&str>) -> Option<String> {
+    dotenv().ok();
+    env::var("AOC_USER")
+        .ok()
+        .filter(|user| !user.is_empty())
+        .or_else(|| session.map(session_hash))
+}
+
+fn session_hash(session: &str) -> String {
+    let mut hasher = FnvHasher::default();
+    hasher.write(session.trim().as_bytes());
+    format!("{:016x}", hasher.finish())
+}
+
+/// Downloads an input and caches it, unless a concurrent runner did it while waiting for the
+/// lock of the file. Error pages are never cached.
+fn download_to_file(url: &str, session: &str, filepath: impl AsRef<Path>) -> Result<String, Error> {
+    let filepath = filepath.as_ref();
+    let _lock = FileLock::acquire(filepath)?;
+    if filepath.exists() {
+        return read_file(filepath);
+    }
+    let contents = client::get(url, session)?;
+    write_to_file(contents.as_bytes(), filepath)?;
+    Ok(contents.trim_end().to_string())
+}
+
+/// Lock file held while a file is downloaded, so that concurrent runners do not download it
+/// twice. Removed when dropped.
+struct FileLock(PathBuf);
+
+impl FileLock {
+    /// Locks taken longer ago are left behind by a crashed runner
+    const TIMEOUT: Duration = Duration::from_secs(60);
+
+    /// Waits until the file is not locked anymore and locks it
+    fn acquire(filepath: &Path) -> Result<Self, Error> {
+        let mut path = filepath.as_os_str().to_owned();
+        path.push(".lock");
+        let path = PathBuf::from(path);
+        let io_error = |source| Error::Io {
+            path: path.clone(),
+            source,
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
         }
-        panic!("Session credentials are missing");
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(Self(path)),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                    let stale = fs::metadata(&path)
+                        .and_then(|metadata| metadata.modified())
+                        .is_ok_and(|modified| {
+                            modified.elapsed().unwrap_or_default() > Self::TIMEOUT
+                        });
+                    match stale {
+                        true => {
+                            let _ = fs::remove_file(&path);
+                        }
+                        false => thread::sleep(Duration::from_millis(100)),
+                    }
+                }
+                Err(e) => return Err(io_error(e)),
+            }
+        }
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
     }
 }
 
-pub fn read_file(filepath: &str) -> String {
-    let file = File::open(filepath).expect("Unable to open file");
+/// Returns the session cookie from `AOC_SESSION`, also looking into `.env`
+pub fn get_session() -> Result<String, Error> {
+    dotenv().ok();
+    env::var("AOC_SESSION").map_err(|_| Error::MissingSession)
+}
+
+pub fn read_file(filepath: impl AsRef<Path>) -> Result<String, Error> {
+    let filepath = filepath.as_ref();
+    let io_error = |source| Error::Io {
+        path: filepath.to_path_buf(),
+        source,
+    };
+    let file = File::open(filepath).map_err(io_error)?;
     let mut buffered_reader = BufReader::new(file);
     let mut contents = String::new();
     buffered_reader
         .read_to_string(&mut contents)
-        .expect("Unable to read file into the string");
-    contents.trim_end().to_string()
+        .map_err(io_error)?;
+    Ok(contents.trim_end().to_string())
 }
 
-fn write_to_file(contents: &[u8], filepath: &str) {
-    let file = File::create(filepath).expect("Unable to create file");
+pub fn write_to_file(contents: &[u8], filepath: impl AsRef<Path>) -> Result<(), Error> {
+    let filepath = filepath.as_ref();
+    let io_error = |source| Error::Io {
+        path: filepath.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = filepath.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let file = File::create(filepath).map_err(io_error)?;
     let mut buffered_writer = BufWriter::new(file);
-    buffered_writer
-        .write_all(contents)
-        .expect("Unable to write to file");
+    buffered_writer.write_all(contents).map_err(io_error)?;
+    buffered_writer.flush().map_err(io_error)
 }
 
-pub fn get_latest_year_and_day() -> (i32, u32) {
-    let now = Utc::now();
-    let last_aoc_day = if now.year() > 2024 { 12 } else { 25 };
-    match now.month() {
-        12 => (now.year(), now.day().min(last_aoc_day)),
-        _ => (now.year() - 1, last_aoc_day),
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::serve_once;
+
+    fn temp_input(name: &str) -> String {
+        let path = env::temp_dir().join(format!("aoc_{}_{}.in", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_download_caches_input() {
+        let (base_url, server) = serve_once(200, "1\n2\n3\n");
+        let filepath = temp_input("download");
+        let url = format!("{}/2021/day/1/input", base_url);
+        let input = download_to_file(&url, "abc", &filepath).unwrap();
+        assert_eq!(input, "1\n2\n3");
+        assert_eq!(read_file(&filepath).unwrap(), "1\n2\n3");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2021/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+        fs::remove_file(&filepath).unwrap();
+    }
+
+    #[test]
+    fn test_download_errors_are_not_cached() {
+        let filepath = temp_input("download_errors");
+        let (base_url, server) = serve_once(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time.\n",
+        );
+        let result = download_to_file(&base_url, "abc", &filepath);
+        server.join().unwrap();
+        assert!(matches!(result, Err(Error::NotUnlocked)));
+        assert!(!Path::new(&filepath).exists());
+
+        let (base_url, server) = serve_once(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        );
+        let result = download_to_file(&base_url, "abc", &filepath);
+        server.join().unwrap();
+        assert!(matches!(result, Err(Error::Http { status: 400, .. })));
+        assert!(!Path::new(&filepath).exists());
+    }
+
+    #[test]
+    fn test_download_waits_for_lock() {
+        let filepath = temp_input("lock");
+        let lock = FileLock::acquire(Path::new(&filepath)).unwrap();
+        let waiting = thread::spawn({
+            let filepath = filepath.clone();
+            // nothing listens there, the input has to come from the first runner
+            move || download_to_file("http://127.0.0.1:9", "abc", &filepath)
+        });
+        thread::sleep(Duration::from_millis(200));
+        write_to_file(b"1\n2\n", &filepath).unwrap();
+        drop(lock);
+        assert_eq!(waiting.join().unwrap().unwrap(), "1\n2");
+        assert!(!Path::new(&format!("{}.lock", filepath)).exists());
+        fs::remove_file(&filepath).unwrap();
+    }
+
+    #[test]
+    fn test_cache_dirs() {
+        let some = |s: &str| Some(OsString::from(s));
+        assert_eq!(
+            xdg_cache_dir(some("/xdg"), some("/home/aoc")),
+            Some(PathBuf::from("/xdg"))
+        );
+        assert_eq!(
+            xdg_cache_dir(some("relative"), some("/home/aoc")),
+            Some(PathBuf::from("/home/aoc/.cache"))
+        );
+        assert_eq!(xdg_cache_dir(None, None), None);
+
+        let root = PathBuf::from("/cache");
+        assert_eq!(
+            input_dirs(root.clone(), Some("alice".to_string())),
+            vec![PathBuf::from("/cache/alice"), root.clone()]
+        );
+        assert_eq!(
+            input_dirs(root.clone(), None),
+            vec![root, PathBuf::from(BUNDLED_INPUTS)]
+        );
+
+        assert_eq!(session_hash("abc"), session_hash("abc\n"));
+        assert_ne!(session_hash("abc"), session_hash("abd"));
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(example_number("2018_13_test.in", 2018, 13), Some(0));
+        assert_eq!(example_number("2020_14_test_2.in", 2020, 14), Some(2));
+        assert_eq!(example_number("2020_14_test_2.in", 2020, 1), None);
+        assert_eq!(example_number("2020_14.in", 2020, 14), None);
+        assert_eq!(example_number("2020_14_test_x.in", 2020, 14), None);
+
+        let numbers = |year, day| {
+            example_inputs(year, day)
+                .unwrap()
+                .into_iter()
+                .map(|(n, path)| (n, path.file_name().unwrap().to_str().unwrap().to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(2018, 13), vec![(1, "2018_13_test.in".to_string())]);
+        assert_eq!(
+            numbers(2020, 14),
+            vec![
+                (1, "2020_14_test_1.in".to_string()),
+                (2, "2020_14_test_2.in".to_string())
+            ]
+        );
+        assert_eq!(
+            numbers(2022, 9),
+            vec![
+                (1, "2022_9_test.in".to_string()),
+                (2, "2022_9_test_2.in".to_string())
+            ]
+        );
+        assert_eq!(numbers(2018, 1), vec![]);
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let result = read_file("inputs/missing.in");
+        assert!(matches!(result, Err(Error::Io { .. })));
     }
 }
@@ -0,0 +1,105 @@
+use std::{fs, thread};
+
+use adventofcode::{parse::ParseError, registry, solve, solve_day, util::Error, Answer};
+
+fn input(name: &str) -> String {
+    fs::read_to_string(format!("{}/inputs/{}", env!("CARGO_MANIFEST_DIR"), name)).unwrap()
+}
+
+#[test]
+fn test_solve_examples() {
+    let example = input("2021_1_test.in");
+    assert_eq!(
+        solve(2021, 1, 1, &example).unwrap(),
+        Some(Answer::Number(7))
+    );
+    assert_eq!(
+        solve(2021, 1, 2, &example).unwrap(),
+        Some(Answer::Number(5))
+    );
+    let example = input("2025_1_test.in");
+    assert_eq!(
+        solve(2025, 1, 1, &example).unwrap(),
+        Some(Answer::Number(3))
+    );
+    assert_eq!(
+        solve(2025, 1, 2, &example).unwrap(),
+        Some(Answer::Number(6))
+    );
+}
+
+#[test]
+fn test_solve_day() {
+    let solution = solve_day(2021, 1, &input("2021_1.in")).unwrap().unwrap();
+    assert_eq!(solution.part1.to_string(), "1477");
+    assert_eq!(solution.part2.to_string(), "1523");
+    let solution = solve_day(2022, 10, &input("2022_10.in")).unwrap().unwrap();
+    assert_eq!(solution.part1.to_string(), "13740");
+    assert_eq!(solution.part2, Answer::Text("ZUPRFECL".to_string()));
+}
+
+#[test]
+fn test_not_implemented() {
+    assert_eq!(solve(2019, 1, 1, "").unwrap(), None);
+    assert_eq!(solve(2021, 26, 1, "").unwrap(), None);
+    assert_eq!(solve(2021, 1, 3, &input("2021_1_test.in")).unwrap(), None);
+}
+
+#[test]
+fn test_invalid_input() {
+    let error = solve(2020, 8, 1, "jmp x").unwrap_err();
+    assert!(matches!(
+        error,
+        Error::Parse(ParseError {
+            line: 1,
+            column: 5,
+            ..
+        })
+    ));
+    let error = solve(2020, 8, 1, "nop +0\nacc +1\nadd +2").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid input at line 3, column 1: unknown instruction `add`"
+    );
+}
+
+#[test]
+fn test_solve_on_threads() {
+    let handles = ["2021_1_test.in", "2021_1.in"].map(|name| {
+        let input = input(name);
+        thread::spawn(move || {
+            (0..5)
+                .map(|_| solve(2021, 1, 1, &input).unwrap().unwrap())
+                .collect::<Vec<_>>()
+        })
+    });
+    let [example, puzzle] = handles.map(|handle| handle.join().unwrap());
+    assert!(example.iter().all(|answer| *answer == Answer::Number(7)));
+    assert!(puzzle.iter().all(|answer| *answer == Answer::Number(1477)));
+}
+
+#[test]
+fn test_variants() {
+    let registry = registry();
+    for (year, day) in registry.available() {
+        let aoc_year = registry.get(year).unwrap();
+        let variants = aoc_year.variants(day);
+        if variants.is_empty() {
+            continue;
+        }
+        let run = match aoc_year.run_day(day).unwrap() {
+            Ok(run) => run,
+            // days whose input is not committed compare their variants on the examples
+            Err(Error::Input { .. }) => continue,
+            Err(e) => panic!("{} day {}: {}", year, day, e),
+        };
+        for variant in variants {
+            let variant_run = aoc_year.run_variant(day, variant).unwrap().unwrap();
+            assert_eq!(
+                variant_run.solution, run.solution,
+                "{} day {} variant {}",
+                year, day, variant
+            );
+        }
+    }
+}
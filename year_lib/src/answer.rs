@@ -0,0 +1,79 @@
+use std::fmt;
+
+/// Answer of a single puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    /// Free-form answer, may span multiple lines (e.g. an image drawn on a grid).
+    Text(String),
+    /// The part has no implemented solution.
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Text(s) if s.contains('\n'))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Self {
+        Answer::Text(lines.join("\n"))
+    }
+}
+
+/// Answers of both parts of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+impl Solution {
+    pub fn new(part1: impl Into<Answer>, part2: impl Into<Answer>) -> Self {
+        Self {
+            part1: part1.into(),
+            part2: part2.into(),
+        }
+    }
+
+    pub fn parts(&self) -> [(u8, &Answer); 2] {
+        [(1, &self.part1), (2, &self.part2)]
+    }
+}
@@ -1,5 +1,11 @@
+mod answer;
+mod registry;
+mod timer;
 mod year;
 
-pub use year::AOCYear;
+pub use answer::{Answer, Solution};
+pub use registry::Registry;
+pub use timer::{Timer, Timings};
+pub use year::{event_days, AOCYear, DayRun};
 
-pub use year_proc_lib::AOCYear;
+pub use year_proc_lib::{aoc_years, AOCYear};
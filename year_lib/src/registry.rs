@@ -0,0 +1,116 @@
+use std::{
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use crate::{AOCYear, DayRun};
+
+/// Stack size of the threads running days, as large as the one of the main thread
+const STACK_SIZE: usize = 8 << 20;
+
+/// Years having solutions, usually built by `aoc_years!` from the `aocYYYY` modules of a crate.
+pub struct Registry<E> {
+    years: BTreeMap<i32, Box<dyn AOCYear<Error = E>>>,
+}
+
+impl<E> Registry<E> {
+    pub fn new(years: Vec<(i32, fn() -> Box<dyn AOCYear<Error = E>>)>) -> Self {
+        Self {
+            years: years.into_iter().map(|(year, new)| (year, new())).collect(),
+        }
+    }
+
+    /// Registered years, in order
+    pub fn years(&self) -> impl Iterator<Item = i32> + '_ {
+        self.years.keys().copied()
+    }
+
+    pub fn get(&self, year: i32) -> Option<&(dyn AOCYear<Error = E> + 'static)> {
+        self.years.get(&year).map(|aoc_year| aoc_year.as_ref())
+    }
+
+    /// Every available (year, day) pair, in order
+    pub fn available(&self) -> Vec<(i32, u32)> {
+        self.years
+            .iter()
+            .flat_map(|(&year, aoc_year)| {
+                aoc_year
+                    .available_days()
+                    .iter()
+                    .map(move |&day| (year, day))
+            })
+            .collect()
+    }
+
+    pub fn is_available(&self, year: i32, day: u32) -> bool {
+        self.get(year)
+            .is_some_and(|aoc_year| aoc_year.available_days().contains(&day))
+    }
+
+    /// Runs the days, or the given variant of them, on `jobs` threads and hands each run to `f`
+    /// in the order of `days`, as soon as the previous ones are handed. Days without a solution
+    /// are handed `None`. A day that panics on a thread resumes its panic on the calling one, in
+    /// its turn.
+    pub fn run_days(
+        &self,
+        days: &[(i32, u32)],
+        variant: Option<&str>,
+        jobs: usize,
+        mut f: impl FnMut(i32, u32, Option<Result<DayRun, E>>),
+    ) where
+        E: Send,
+    {
+        let run = |(year, day): (i32, u32)| {
+            let aoc_year = self.get(year)?;
+            match variant {
+                Some(variant) => aoc_year.run_variant(day, variant),
+                None => aoc_year.run_day(day),
+            }
+        };
+        if jobs <= 1 {
+            days.iter()
+                .for_each(|&(year, day)| f(year, day, run((year, day))));
+            return;
+        }
+        let next = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+        thread::scope(|scope| {
+            for _ in 0..jobs.min(days.len()) {
+                let sender = sender.clone();
+                let (next, run) = (&next, &run);
+                thread::Builder::new()
+                    .stack_size(STACK_SIZE)
+                    .spawn_scoped(scope, move || {
+                        while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                            let run = panic::catch_unwind(AssertUnwindSafe(|| run(day)));
+                            if sender.send((day, run)).is_err() {
+                                break;
+                            }
+                        }
+                    })
+                    .expect("Unable to spawn a thread");
+            }
+            drop(sender);
+            // runs finishing early wait for the previous days
+            let mut pending = BTreeMap::new();
+            let mut handed = 0;
+            for (day, run) in receiver {
+                pending.insert(day, run);
+                while let Some(run) = days.get(handed).and_then(|day| pending.remove(day)) {
+                    let (year, day) = days[handed];
+                    f(
+                        year,
+                        day,
+                        run.unwrap_or_else(|payload| panic::resume_unwind(payload)),
+                    );
+                    handed += 1;
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::{Duration, Instant};
+
+/// Time spent in each phase of a day.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub input: Duration,
+    /// Parsing and any preprocessing shared by both parts.
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.input + self.parse + self.part1 + self.part2
+    }
+}
+
+/// Measures the phases of a day. Each call adds to the time of its phase.
+#[derive(Debug, Default)]
+pub struct Timer {
+    timings: Timings,
+}
+
+impl Timer {
+    pub fn input<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        Self::measure(&mut self.timings.input, f)
+    }
+
+    pub fn parse<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        Self::measure(&mut self.timings.parse, f)
+    }
+
+    pub fn part1<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        Self::measure(&mut self.timings.part1, f)
+    }
+
+    pub fn part2<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        Self::measure(&mut self.timings.part2, f)
+    }
+
+    pub fn timings(&self) -> Timings {
+        self.timings
+    }
+
+    fn measure<T>(phase: &mut Duration, f: impl FnOnce() -> T) -> T {
+        let now = Instant::now();
+        let ret = f();
+        *phase += now.elapsed();
+        ret
+    }
+}
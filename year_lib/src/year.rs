@@ -1,91 +1,83 @@
-pub trait AOCYear {
-    fn new() -> Box<dyn AOCYear>
+use crate::{Solution, Timer, Timings};
+
+/// Number of days of the event of the year, 12 since 2025
+pub const fn event_days(year: i32) -> u32 {
+    if year > 2024 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Years are shared between the threads running their days.
+pub trait AOCYear: Sync {
+    /// Error of a day that cannot be solved, such as a missing or invalid input
+    type Error;
+
+    fn new() -> Box<dyn AOCYear<Error = Self::Error>>
     where
         Self: Sized;
-    fn day1(&self);
-    fn day2(&self);
-    fn day3(&self);
-    fn day4(&self);
-    fn day5(&self);
-    fn day6(&self);
-    fn day7(&self);
-    fn day8(&self);
-    fn day9(&self);
-    fn day10(&self);
-    fn day11(&self);
-    fn day12(&self);
-    fn day13(&self);
-    fn day14(&self);
-    fn day15(&self);
-    fn day16(&self);
-    fn day17(&self);
-    fn day18(&self);
-    fn day19(&self);
-    fn day20(&self);
-    fn day21(&self);
-    fn day22(&self);
-    fn day23(&self);
-    fn day24(&self);
-    fn day25(&self);
+    fn year(&self) -> i32;
+    /// Number of days of the event
+    fn day_count(&self) -> u32;
+    /// Days having a solution, in order
+    fn available_days(&self) -> &'static [u32];
+    /// Solves the day, `None` if it has no solution
+    fn solve_day(&self, day: u32, timer: &mut Timer) -> Option<Result<Solution, Self::Error>>;
+    /// Names of the alternative implementations of the day, its `run_<name>` functions
+    fn variants(&self, day: u32) -> &'static [&'static str];
+    /// Solves the day with one of its variants, `None` if it has no such variant
+    fn solve_variant(
+        &self,
+        day: u32,
+        variant: &str,
+        timer: &mut Timer,
+    ) -> Option<Result<Solution, Self::Error>>;
+}
+
+/// Solution of a day together with the time spent in each of its phases.
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub day: u32,
+    pub solution: Solution,
+    pub timings: Timings,
 }
 
-impl dyn AOCYear {
-    pub fn run_all(&self) {
-        self.day1();
-        self.day2();
-        self.day3();
-        self.day4();
-        self.day5();
-        self.day6();
-        self.day7();
-        self.day8();
-        self.day9();
-        self.day10();
-        self.day11();
-        self.day12();
-        self.day13();
-        self.day14();
-        self.day15();
-        self.day16();
-        self.day17();
-        self.day18();
-        self.day19();
-        self.day20();
-        self.day21();
-        self.day22();
-        self.day23();
-        self.day24();
-        self.day25();
+impl<E> dyn AOCYear<Error = E> + '_ {
+    /// Runs every available day, up to the first one failing
+    pub fn run_all(&self) -> Result<Vec<DayRun>, E> {
+        self.available_days()
+            .iter()
+            .filter_map(|&day| self.run_day(day))
+            .collect()
+    }
+
+    /// Days of the event, in order
+    pub fn days(&self) -> std::ops::RangeInclusive<u32> {
+        1..=self.day_count()
+    }
+
+    /// Runs the day, if it is available
+    pub fn run_day(&self, day: u32) -> Option<Result<DayRun, E>> {
+        self.run_with(day, |timer| self.solve_day(day, timer))
+    }
+
+    /// Runs a variant of the day, if the day has it
+    pub fn run_variant(&self, day: u32, variant: &str) -> Option<Result<DayRun, E>> {
+        self.run_with(day, |timer| self.solve_variant(day, variant, timer))
     }
 
-    pub fn run_day(&self, day: u32) {
-        match day {
-            1 => self.day1(),
-            2 => self.day2(),
-            3 => self.day3(),
-            4 => self.day4(),
-            5 => self.day5(),
-            6 => self.day6(),
-            7 => self.day7(),
-            8 => self.day8(),
-            9 => self.day9(),
-            10 => self.day10(),
-            11 => self.day11(),
-            12 => self.day12(),
-            13 => self.day13(),
-            14 => self.day14(),
-            15 => self.day15(),
-            16 => self.day16(),
-            17 => self.day17(),
-            18 => self.day18(),
-            19 => self.day19(),
-            20 => self.day20(),
-            21 => self.day21(),
-            22 => self.day22(),
-            23 => self.day23(),
-            24 => self.day24(),
-            25 => self.day25(),
-            _ => unreachable!(),
-        }
+    fn run_with(
+        &self,
+        day: u32,
+        solve: impl FnOnce(&mut Timer) -> Option<Result<Solution, E>>,
+    ) -> Option<Result<DayRun, E>> {
+        let mut timer = Timer::default();
+        let solution = solve(&mut timer)?;
+        Some(solution.map(|solution| DayRun {
+            day,
+            solution,
+            timings: timer.timings(),
+        }))
     }
 }
@@ -11,50 +11,166 @@ pub fn aoc_year_maker(item: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(item as DeriveInput);
     let struct_name = &ast.ident;
 
-    let full = (1..=25u8).collect::<Vec<_>>();
+    let year = struct_name
+        .to_string()
+        .trim_start_matches("AOC")
+        .parse::<i32>()
+        .expect("AOCYear structs are named AOCYYYY");
     let available = return_available_days(&struct_name.to_string()).unwrap();
-    let not_available = full
-        .iter()
-        .filter(|item| !available.contains(item))
-        .collect::<Vec<_>>();
+    let available_days = available.iter().map(|&day| day as u32).collect::<Vec<_>>();
+    let last_day = available_days.last().copied().unwrap_or(0);
     let available_module_names = available
         .iter()
         .map(|item| format!("day{}", item))
         .map(|s| Ident::new(&s, Span::call_site()))
         .collect::<Vec<_>>();
-    let not_available_module_names = not_available
+    let variants = available
         .iter()
-        .map(|item| format!("day{}", item))
-        .map(|s| Ident::new(&s, Span::call_site()))
+        .map(|&day| return_day_variants(&struct_name.to_string(), day).unwrap())
         .collect::<Vec<_>>();
+    let variant_names = variants
+        .iter()
+        .map(|names| names.iter().map(|(name, _)| name).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut variant_days = Vec::new();
+    let mut variant_modules = Vec::new();
+    let mut variant_fn_names = Vec::new();
+    let mut variant_fns = Vec::new();
+    for ((day, module), names) in available_days
+        .iter()
+        .zip(&available_module_names)
+        .zip(&variants)
+    {
+        for (name, f) in names {
+            variant_days.push(day);
+            variant_modules.push(module);
+            variant_fn_names.push(name);
+            variant_fns.push(f);
+        }
+    }
 
     let expanded = quote! {
       #(
         mod #available_module_names;
       )*
+      const _: () = assert!(
+        #last_day <= year_lib::event_days(#year),
+        "a day is beyond the last day of the event"
+      );
       impl AOCYear for #struct_name {
-        fn new() -> Box<dyn AOCYear> {
+        type Error = crate::util::Error;
+
+        fn new() -> Box<dyn AOCYear<Error = Self::Error>> {
           Box::new(Self {})
         }
-        #(
-          fn #available_module_names(&self) {
-            #available_module_names::run();
+        fn year(&self) -> i32 {
+          #year
+        }
+        fn day_count(&self) -> u32 {
+          year_lib::event_days(#year)
+        }
+        fn available_days(&self) -> &'static [u32] {
+          &[#(#available_days),*]
+        }
+        fn solve_day(
+          &self,
+          day: u32,
+          timer: &mut year_lib::Timer,
+        ) -> Option<Result<year_lib::Solution, Self::Error>> {
+          match day {
+            #(
+              #available_days => Some(#available_module_names::run(timer)),
+            )*
+            _ => None,
           }
-        )*
-        #(fn #not_available_module_names(&self) {
-            unimplemented!("day {}", #not_available);
-        })*
+        }
+        fn variants(&self, day: u32) -> &'static [&'static str] {
+          match day {
+            #(
+              #available_days => &[#(#variant_names),*],
+            )*
+            _ => &[],
+          }
+        }
+        fn solve_variant(
+          &self,
+          day: u32,
+          variant: &str,
+          timer: &mut year_lib::Timer,
+        ) -> Option<Result<year_lib::Solution, Self::Error>> {
+          match (day, variant) {
+            #(
+              (#variant_days, #variant_fn_names) => Some(#variant_modules::#variant_fns(timer)),
+            )*
+            _ => None,
+          }
+        }
       }
     };
     expanded.into()
 }
 
-fn return_available_days(struct_name: &str) -> anyhow::Result<Vec<u8>> {
-    let module_path = std::env::current_dir()?
-        .join("src")
-        .join(struct_name.to_lowercase());
+/// Declares every `aocYYYY` module found in `src` and a public `registry()` function returning
+/// a `year_lib::Registry` of their years. Each module has to define its `AOCYYYY` struct, and
+/// the days return the `crate::util::Error` of the crate when they cannot be solved.
+#[proc_macro]
+pub fn aoc_years(_item: TokenStream) -> TokenStream {
+    let years = return_available_years().unwrap();
+    let module_names = years
+        .iter()
+        .map(|year| Ident::new(&format!("aoc{}", year), Span::call_site()))
+        .collect::<Vec<_>>();
+    let struct_names = years
+        .iter()
+        .map(|year| Ident::new(&format!("AOC{}", year), Span::call_site()))
+        .collect::<Vec<_>>();
+
+    let expanded = quote! {
+      #(
+        mod #module_names;
+      )*
+      pub fn registry() -> year_lib::Registry<crate::util::Error> {
+        year_lib::Registry::new(vec![
+          #(
+            (#years, <#module_names::#struct_names as year_lib::AOCYear>::new as fn() -> Box<dyn year_lib::AOCYear<Error = crate::util::Error>>),
+          )*
+        ])
+      }
+    };
+    expanded.into()
+}
 
+/// The manifest dir of the crate using the macros, so that it builds from any directory
+fn crate_dir() -> anyhow::Result<std::path::PathBuf> {
+    match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => Ok(std::path::PathBuf::from(dir)),
+        None => Ok(std::env::current_dir()?),
+    }
+}
+
+fn return_available_years() -> anyhow::Result<Vec<i32>> {
     let mut years = Vec::new();
+    for f in std::fs::read_dir(crate_dir()?.join("src"))? {
+        let f = f?;
+        let filename = f.file_name();
+        if let Some(year) = filename
+            .to_str()
+            .and_then(|filename| filename.strip_prefix("aoc"))
+            .and_then(|year| year.parse::<i32>().ok())
+        {
+            if f.path().join("mod.rs").exists() {
+                years.push(year);
+            }
+        }
+    }
+    years.sort_unstable();
+    Ok(years)
+}
+
+fn return_available_days(struct_name: &str) -> anyhow::Result<Vec<u8>> {
+    let module_path = crate_dir()?.join("src").join(struct_name.to_lowercase());
+
+    let mut days = Vec::new();
     for f in std::fs::read_dir(module_path)? {
         let f = f?;
         let filename = f.file_name();
@@ -64,9 +180,33 @@ fn return_available_days(struct_name: &str) -> anyhow::Result<Vec<u8>> {
                     .trim_start_matches("day")
                     .trim_end_matches(".rs")
                     .parse::<u8>()?;
-                  years.push(day);
+                if (1..=25).contains(&day) {
+                    days.push(day);
+                }
             }
         }
     }
-    Ok(years)
+    days.sort_unstable();
+    Ok(days)
+}
+
+/// Variants of a day: its public `run_<name>` functions, as `(name, function)` pairs
+fn return_day_variants(struct_name: &str, day: u8) -> anyhow::Result<Vec<(String, Ident)>> {
+    let path = crate_dir()?
+        .join("src")
+        .join(struct_name.to_lowercase())
+        .join(format!("day{}.rs", day));
+    let file = syn::parse_file(&std::fs::read_to_string(path)?)?;
+    Ok(file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Fn(f) if !matches!(f.vis, syn::Visibility::Inherited) => Some(&f.sig.ident),
+            _ => None,
+        })
+        .filter_map(|ident| {
+            let name = ident.to_string().strip_prefix("run_")?.to_string();
+            Some((name, ident.clone()))
+        })
+        .collect())
 }